@@ -18,3 +18,13 @@ pub fn range_end_gt_len(re: usize, len: usize) -> String {
 pub fn range_order_rev(start: usize, end: usize) -> String {
     format!("Range start {start} is greater than range end {end}.")
 }
+
+/// Message for cases lengths of operands are not equal.
+pub fn len_mismatch(lhs: usize, rhs: usize) -> String {
+    format!("Length {lhs} is not equal to length {rhs}.")
+}
+
+/// Message for cases index is too large to be contained by any length.
+pub fn index_too_large(index: usize) -> String {
+    format!("Index {index} is too large to be contained by length.")
+}
@@ -35,3 +35,60 @@ pub fn normalize_range<R: RangeBounds<usize>>(range: R, len: usize) -> Range<usi
 
     s..e
 }
+
+/// Normalize range for index, or returns `None` if it is invalid.
+pub fn checked_range<R: RangeBounds<usize>>(range: R, len: usize) -> Option<Range<usize>> {
+    let s = match range.start_bound() {
+        Bound::Included(x) => *x,
+        Bound::Excluded(x) => x.checked_add(1)?,
+        Bound::Unbounded => 0,
+    };
+    let e = match range.end_bound() {
+        Bound::Included(x) => x.checked_add(1)?,
+        Bound::Excluded(x) => *x,
+        Bound::Unbounded => len,
+    };
+
+    (s <= e && e <= len).then_some(s..e)
+}
+
+/// Check lengths of operands are equal.
+///
+/// # Panics
+///
+/// Panics if `lhs` and `rhs` are not equal.
+#[track_caller]
+pub fn check_len_eq(lhs: usize, rhs: usize) {
+    if lhs != rhs {
+        panic_any(msg::len_mismatch(lhs, rhs));
+    }
+}
+
+/// Folds `n` copies of `value` by `f` in O(log n) steps.
+///
+/// `f` must be associative. Returns `None` if `n` is zero.
+pub fn repeat_fold<T, F>(value: &T, n: usize, f: F) -> Option<T>
+where
+    T: Clone,
+    F: Fn(T, T) -> T,
+{
+    let mut ret = None as Option<T>;
+    let mut base = value.clone();
+    let mut n = n;
+
+    while n > 0 {
+        if n & 1 == 1 {
+            ret = Some(match ret {
+                Some(x) => f(x, base.clone()),
+                None => base.clone(),
+            });
+        }
+
+        n >>= 1;
+        if n > 0 {
+            base = f(base.clone(), base);
+        }
+    }
+
+    ret
+}
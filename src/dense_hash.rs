@@ -0,0 +1,120 @@
+//! Provider of [`DenseHash`].
+
+use crate::BTreeStorage;
+use crate::SparseStorage;
+use crate::prelude::*;
+use std::hash::{Hash, Hasher};
+
+/// Hashing adapter compatible with dense slices.
+///
+/// [`Hash`] implementation of sparse types hashes runs of same values.
+/// So, its result differs from the hash of dense slice with identical
+/// contents. This type hashes elements in the same way as `[T]`, so
+/// that its result is equal to the hash of [`to_vec`] result.
+///
+/// This type is created by [`SparseVec::dense_hash`] or
+/// [`SparseSlice::dense_hash`]. See its documentation for more.
+///
+/// [`to_vec`]: SparseVec::to_vec
+///
+/// # Costs
+///
+/// To match the hash of `[T]` exactly for any `T` and any hasher, the
+/// contents are cloned into a temporary dense vector on each hashing.
+/// So, hashing takes time and memory proportional to the length,
+/// rather than to the number of stored elements.
+///
+/// # Lookups
+///
+/// This type implements [`Eq`] and [`PartialEq`] against `[T]` and
+/// [`Vec<T>`]. However, dense keys can not be borrowed as this type,
+/// so `HashMap<Vec<T>, V>` can not be looked up by this type directly.
+/// To find the dense key, compare hashes first and then check equality
+/// with this type (e.g. via raw entry API of hash table crates).
+///
+/// # Examples
+///
+/// ```
+/// # use sparse_vector::prelude::*;
+/// # use std::hash::{BuildHasher, RandomState};
+/// let v = SparseVec::<_>::from_iter([1, 0, 3, 0, 5]);
+/// let s = RandomState::new();
+/// let sparse_hash = s.hash_one(v.dense_hash());
+/// let dense_hash = s.hash_one(v.to_vec());
+/// assert_eq!(sparse_hash, dense_hash);
+/// assert!(v.dense_hash() == v.to_vec());
+/// ```
+#[derive(Debug)]
+pub struct DenseHash<'a, T, S = BTreeStorage<T>>
+where
+    T: PartialEq,
+    S: SparseStorage<T>,
+{
+    /// Target slice.
+    slice: SparseSlice<'a, T, S>,
+}
+
+impl<'a, T, S> DenseHash<'a, T, S>
+where
+    T: PartialEq,
+    S: SparseStorage<T>,
+{
+    /// Creates a new instance.
+    pub(crate) fn new(slice: SparseSlice<'a, T, S>) -> Self {
+        Self { slice }
+    }
+}
+
+impl<T, S> Eq for DenseHash<'_, T, S>
+where
+    T: Eq,
+    S: SparseStorage<T>,
+{
+    // nop.
+}
+
+/// Dense compatible hashing.
+///
+/// Contents are cloned into a temporary vector and hashed as `[T]`, so
+/// that [`Hash::hash_slice`] of `T` sees the same contiguous slice.
+impl<T, S> Hash for DenseHash<'_, T, S>
+where
+    T: PartialEq + Clone + Hash,
+    S: SparseStorage<T>,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.slice.to_vec().as_slice().hash(state);
+    }
+}
+
+impl<'a, 'b, T, U, S, S2> PartialEq<DenseHash<'b, U, S2>> for DenseHash<'a, T, S>
+where
+    T: PartialEq + PartialEq<U>,
+    U: PartialEq,
+    S: SparseStorage<T>,
+    S2: SparseStorage<U>,
+{
+    fn eq(&self, other: &DenseHash<'b, U, S2>) -> bool {
+        self.slice == other.slice
+    }
+}
+
+impl<T, U, S> PartialEq<[U]> for DenseHash<'_, T, S>
+where
+    T: PartialEq + PartialEq<U>,
+    S: SparseStorage<T>,
+{
+    fn eq(&self, other: &[U]) -> bool {
+        self.slice == *other
+    }
+}
+
+impl<T, U, S> PartialEq<Vec<U>> for DenseHash<'_, T, S>
+where
+    T: PartialEq + PartialEq<U>,
+    S: SparseStorage<T>,
+{
+    fn eq(&self, other: &Vec<U>) -> bool {
+        self.slice == *other
+    }
+}
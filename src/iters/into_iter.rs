@@ -1,5 +1,7 @@
 //! Provider of [`IntoIter`].
 
+use crate::BTreeStorage;
+use crate::SparseStorage;
 use crate::prelude::*;
 use only_one::prelude::*;
 use std::fmt::Debug;
@@ -14,23 +16,25 @@ use std::ops::Range;
 ///
 /// [`into_iter`]: crate::SparseVec::into_iter
 #[derive(Clone, Debug)]
-pub struct IntoIter<T>
+pub struct IntoIter<T, S = BTreeStorage<T>>
 where
     T: PartialEq,
+    S: SparseStorage<T>,
 {
     /// Underlying collection.
-    vec: One<SparseVec<T>>,
+    vec: One<SparseVec<T, S>>,
 
     /// Iterating range.
     range: Range<usize>,
 }
 
-impl<T> IntoIter<T>
+impl<T, S> IntoIter<T, S>
 where
     T: PartialEq,
+    S: SparseStorage<T>,
 {
     /// Creates a new instance.
-    pub(crate) fn new(vec: SparseVec<T>) -> Self {
+    pub(crate) fn new(vec: SparseVec<T, S>) -> Self {
         let vec = One::new(vec);
         let range = 0..vec.len;
         Self { vec, range }
@@ -42,9 +46,10 @@ where
     }
 }
 
-impl<T> Default for IntoIter<T>
+impl<T, S> Default for IntoIter<T, S>
 where
     T: PartialEq,
+    S: SparseStorage<T>,
 {
     fn default() -> Self {
         Self {
@@ -54,23 +59,26 @@ where
     }
 }
 
-impl<T> ExactSizeIterator for IntoIter<T>
+impl<T, S> ExactSizeIterator for IntoIter<T, S>
 where
     T: PartialEq,
+    S: SparseStorage<T>,
 {
     // nop.
 }
 
-impl<T> FusedIterator for IntoIter<T>
+impl<T, S> FusedIterator for IntoIter<T, S>
 where
     T: PartialEq,
+    S: SparseStorage<T>,
 {
     // nop.
 }
 
-impl<T> Iterator for IntoIter<T>
+impl<T, S> Iterator for IntoIter<T, S>
 where
     T: PartialEq,
+    S: SparseStorage<T>,
 {
     type Item = T;
 
@@ -89,9 +97,10 @@ where
     }
 }
 
-impl<T> DoubleEndedIterator for IntoIter<T>
+impl<T, S> DoubleEndedIterator for IntoIter<T, S>
 where
     T: PartialEq,
+    S: SparseStorage<T>,
 {
     fn next_back(&mut self) -> Option<Self::Item> {
         if self.is_end() {
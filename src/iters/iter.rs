@@ -1,6 +1,7 @@
 //! Provider of [`Iter`].
 
-use crate::aliases::*;
+use crate::BTreeStorage;
+use crate::SparseStorage;
 use crate::common::*;
 use crate::prelude::*;
 use only_one::prelude::*;
@@ -13,15 +14,16 @@ use std::ops::Range;
 /// See its documentation for more.
 #[derive(Debug)]
 #[must_use = msg::iter_must_use!()]
-pub struct Iter<'a, T>
+pub struct Iter<'a, T, S = BTreeStorage<T>>
 where
     T: PartialEq,
+    S: SparseStorage<T> + 'a,
 {
     /// Padding value.
     padding: One<&'a T>,
 
     /// Underlying map range.
-    map_range: One<MapRange<'a, T>>,
+    map_range: One<S::Range<'a>>,
 
     /// Iterating range.
     idx_range: Range<usize>,
@@ -33,12 +35,13 @@ where
     tail_memo: Option<(&'a usize, &'a T)>,
 }
 
-impl<'a, T> Iter<'a, T>
+impl<'a, T, S> Iter<'a, T, S>
 where
     T: PartialEq,
+    S: SparseStorage<T> + 'a,
 {
     /// Creates a new instance.
-    pub(crate) fn new(vec: &'a SparseVec<T>, range: Range<usize>) -> Self {
+    pub(crate) fn new(vec: &'a SparseVec<T, S>, range: Range<usize>) -> Self {
         Self {
             padding: One::new(vec.padding_ref()),
             map_range: One::new(vec.map.range(range.clone())),
@@ -59,9 +62,10 @@ where
 /// # TODO for future
 ///
 /// Currently `T` requires [`Clone`]. This is current limitation.
-impl<T> Clone for Iter<'_, T>
+impl<'a, T, S> Clone for Iter<'a, T, S>
 where
     T: PartialEq + Clone,
+    S: SparseStorage<T> + 'a,
 {
     fn clone(&self) -> Self {
         Self {
@@ -74,9 +78,10 @@ where
     }
 }
 
-impl<T> Default for Iter<'_, T>
+impl<'a, T, S> Default for Iter<'a, T, S>
 where
     T: PartialEq,
+    S: SparseStorage<T> + 'a,
 {
     fn default() -> Self {
         Self {
@@ -89,23 +94,26 @@ where
     }
 }
 
-impl<T> ExactSizeIterator for Iter<'_, T>
+impl<'a, T, S> ExactSizeIterator for Iter<'a, T, S>
 where
     T: PartialEq,
+    S: SparseStorage<T> + 'a,
 {
     // nop.
 }
 
-impl<T> FusedIterator for Iter<'_, T>
+impl<'a, T, S> FusedIterator for Iter<'a, T, S>
 where
     T: PartialEq,
+    S: SparseStorage<T> + 'a,
 {
     // nop.
 }
 
-impl<'a, T> Iterator for Iter<'a, T>
+impl<'a, T, S> Iterator for Iter<'a, T, S>
 where
     T: PartialEq,
+    S: SparseStorage<T> + 'a,
 {
     type Item = &'a T;
 
@@ -137,9 +145,10 @@ where
     }
 }
 
-impl<'a, T> DoubleEndedIterator for Iter<'a, T>
+impl<'a, T, S> DoubleEndedIterator for Iter<'a, T, S>
 where
     T: PartialEq,
+    S: SparseStorage<T> + 'a,
 {
     fn next_back(&mut self) -> Option<Self::Item> {
         if self.is_end() {
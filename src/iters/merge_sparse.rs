@@ -0,0 +1,124 @@
+//! Provider of [`MergeSparse`].
+
+use crate::BTreeStorage;
+use crate::SparseStorage;
+use crate::common::*;
+use crate::iters::*;
+use std::iter::FusedIterator;
+
+/// A sparse merge iterator over the elements of some slices.
+///
+/// This iterator yields `(index, values)` for each index where at least
+/// one slice has none padding element, in index order. Slice without none
+/// padding element at the index is `None` in `values`, meaning its padding
+/// value. Indexes are limited to the shortest slice length.
+///
+/// This type is created by [`SparseSlice::merge_sparse`].
+/// See its documentation for more.
+///
+/// [`SparseSlice::merge_sparse`]: crate::SparseSlice::merge_sparse
+#[derive(Debug)]
+#[must_use = msg::iter_must_use!()]
+pub struct MergeSparse<'a, T, const N: usize, S = BTreeStorage<T>>
+where
+    T: PartialEq,
+    S: SparseStorage<T> + 'a,
+{
+    /// Padding of each slice.
+    paddings: [&'a T; N],
+
+    /// Reader of each slice.
+    readers: [SparseReader<'a, T, S>; N],
+
+    /// Iterating head memo of each slice.
+    heads: [Option<(usize, &'a T)>; N],
+
+    /// Iterating tail memo of each slice.
+    tails: [Option<(usize, &'a T)>; N],
+}
+
+impl<'a, T, const N: usize, S> MergeSparse<'a, T, N, S>
+where
+    T: PartialEq,
+    S: SparseStorage<T> + 'a,
+{
+    /// Creates a new instance.
+    pub(crate) fn new(paddings: [&'a T; N], readers: [SparseReader<'a, T, S>; N]) -> Self {
+        Self {
+            paddings,
+            readers,
+            heads: [None; N],
+            tails: [None; N],
+        }
+    }
+
+    /// Returns padding values of each slice.
+    pub fn paddings(&self) -> [&'a T; N] {
+        self.paddings
+    }
+
+    /// Returns item made from memos of each slice.
+    fn merge(
+        memos: &mut [Option<(usize, &'a T)>; N],
+        pick: fn(usize, usize) -> usize,
+    ) -> Option<(usize, [Option<&'a T>; N])> {
+        let indexs = memos.iter().flatten().map(|x| x.0);
+        let index = indexs.reduce(pick)?;
+        let values = memos.each_mut().map(|x| x.take_if(|x| x.0 == index));
+        Some((index, values.map(|x| x.map(|x| x.1))))
+    }
+}
+
+impl<'a, T, const N: usize, S> FusedIterator for MergeSparse<'a, T, N, S>
+where
+    T: PartialEq,
+    S: SparseStorage<T> + 'a,
+{
+    // nop.
+}
+
+impl<'a, T, const N: usize, S> Iterator for MergeSparse<'a, T, N, S>
+where
+    T: PartialEq,
+    S: SparseStorage<T> + 'a,
+{
+    type Item = (usize, [Option<&'a T>; N]);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for i in 0..N {
+            if self.heads[i].is_none() {
+                self.heads[i] = self.readers[i].next().or_else(|| self.tails[i].take());
+            }
+        }
+
+        Self::merge(&mut self.heads, usize::min)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let hints = (0..N).map(|i| {
+            let memo_len = self.heads[i].iter().chain(&self.tails[i]).count();
+            let hint = self.readers[i].size_hint();
+            (hint.0 + memo_len, hint.1.unwrap() + memo_len)
+        });
+
+        hints.fold((0, Some(0)), |acc, x| {
+            (usize::max(acc.0, x.0), acc.1.map(|y| y + x.1))
+        })
+    }
+}
+
+impl<'a, T, const N: usize, S> DoubleEndedIterator for MergeSparse<'a, T, N, S>
+where
+    T: PartialEq,
+    S: SparseStorage<T> + 'a,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        for i in 0..N {
+            if self.tails[i].is_none() {
+                self.tails[i] = self.readers[i].next_back().or_else(|| self.heads[i].take());
+            }
+        }
+
+        Self::merge(&mut self.tails, usize::max)
+    }
+}
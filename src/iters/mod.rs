@@ -2,14 +2,20 @@
 
 pub use into_iter::*;
 pub use iter::*;
+pub use merge_sparse::*;
+pub use positions_of::*;
 pub use sparse_reader::*;
 pub use sparse_writer::*;
 pub use sparse_writer_map::*;
+pub use sparse_zip::*;
 pub use splice::*;
 
 mod into_iter;
 mod iter;
+mod merge_sparse;
+mod positions_of;
 mod sparse_reader;
 mod sparse_writer;
 mod sparse_writer_map;
+mod sparse_zip;
 mod splice;
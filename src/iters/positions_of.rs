@@ -0,0 +1,109 @@
+//! Provider of [`PositionsOf`].
+
+use crate::BTreeStorage;
+use crate::SparseStorage;
+use crate::common::*;
+use crate::iters::*;
+use std::iter::FusedIterator;
+
+/// An iterator over the indexes of elements equal to a value.
+///
+/// This type is created by [`SparseSlice::positions_of`].
+/// See its documentation for more.
+///
+/// [`SparseSlice::positions_of`]: crate::SparseSlice::positions_of
+#[derive(Debug)]
+#[must_use = msg::iter_must_use!()]
+pub struct PositionsOf<'a, T, S = BTreeStorage<T>>
+where
+    T: PartialEq,
+    S: SparseStorage<T> + 'a,
+{
+    /// Searching value.
+    value: &'a T,
+
+    /// Flag that padding value is equal to searching value.
+    padding_hit: bool,
+
+    /// Reader of none padding elements.
+    reader: SparseReader<'a, T, S>,
+
+    /// Head memo of none padding elements.
+    head: Option<(usize, &'a T)>,
+
+    /// Next index to examine.
+    index: usize,
+
+    /// Slice length.
+    len: usize,
+}
+
+impl<'a, T, S> PositionsOf<'a, T, S>
+where
+    T: PartialEq,
+    S: SparseStorage<T> + 'a,
+{
+    /// Creates a new instance.
+    pub(crate) fn new(
+        value: &'a T,
+        padding: &'a T,
+        reader: SparseReader<'a, T, S>,
+        len: usize,
+    ) -> Self {
+        Self {
+            value,
+            padding_hit: padding == value,
+            reader,
+            head: None,
+            index: 0,
+            len,
+        }
+    }
+}
+
+impl<'a, T, S> FusedIterator for PositionsOf<'a, T, S>
+where
+    T: PartialEq,
+    S: SparseStorage<T> + 'a,
+{
+    // nop.
+}
+
+impl<'a, T, S> Iterator for PositionsOf<'a, T, S>
+where
+    T: PartialEq,
+    S: SparseStorage<T> + 'a,
+{
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.index < self.len {
+            if self.head.is_none() {
+                self.head = self.reader.next();
+            }
+
+            let index = self.index;
+            match self.head {
+                Some((i, value)) if i == index => {
+                    self.head = None;
+                    self.index += 1;
+                    if value == self.value {
+                        return Some(index);
+                    }
+                }
+                _ if self.padding_hit => {
+                    self.index += 1;
+                    return Some(index);
+                }
+                Some((i, _)) => self.index = i,
+                None => self.index = self.len,
+            }
+        }
+
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.len - self.index))
+    }
+}
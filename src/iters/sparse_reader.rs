@@ -1,9 +1,11 @@
 //! Provider of [`SparseReader`].
 
-use crate::aliases::*;
+use crate::BTreeStorage;
+use crate::SparseStorage;
 use crate::common::*;
 use crate::prelude::*;
 use only_one::prelude::*;
+use std::fmt::{self, Debug, Formatter};
 use std::iter::FusedIterator;
 use std::ops::Range;
 
@@ -11,11 +13,11 @@ use std::ops::Range;
 ///
 /// This type is created by [`SparseVec::sparse_reader`].
 /// See its documentation for more.
-#[derive(Debug)]
 #[must_use = msg::iter_must_use!()]
-pub struct SparseReader<'a, T>
+pub struct SparseReader<'a, T, S = BTreeStorage<T>>
 where
-    T: PartialEq,
+    T: PartialEq + 'a,
+    S: SparseStorage<T> + 'a,
 {
     /// Underlying sparse vector length.
     len: usize,
@@ -27,15 +29,16 @@ where
     idx_range: Range<usize>,
 
     /// Iterating range of underlying sparse vector map.
-    map_range: One<MapRange<'a, T>>,
+    map_range: One<S::Range<'a>>,
 }
 
-impl<'a, T> SparseReader<'a, T>
+impl<'a, T, S> SparseReader<'a, T, S>
 where
-    T: PartialEq,
+    T: PartialEq + 'a,
+    S: SparseStorage<T> + 'a,
 {
     /// Creates a new instance.
-    pub(crate) fn new(vec: &'a SparseVec<T>, range: Range<usize>) -> Self {
+    pub(crate) fn new(vec: &'a SparseVec<T, S>, range: Range<usize>) -> Self {
         Self {
             len: vec.len(),
             nnp: vec.nnp(),
@@ -55,9 +58,10 @@ where
 /// # TODO for future
 ///
 /// Currently `T` requires [`Clone`]. This is current limitation.
-impl<T> Clone for SparseReader<'_, T>
+impl<'a, T, S> Clone for SparseReader<'a, T, S>
 where
-    T: PartialEq + Clone,
+    T: PartialEq + Clone + 'a,
+    S: SparseStorage<T> + 'a,
 {
     fn clone(&self) -> Self {
         Self {
@@ -69,9 +73,25 @@ where
     }
 }
 
-impl<T> Default for SparseReader<'_, T>
+/// Storage range is omitted, since it may not implement [`Debug`].
+impl<'a, T, S> Debug for SparseReader<'a, T, S>
 where
-    T: PartialEq,
+    T: PartialEq + 'a,
+    S: SparseStorage<T> + 'a,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SparseReader")
+            .field("len", &self.len)
+            .field("nnp", &self.nnp)
+            .field("idx_range", &self.idx_range)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a, T, S> Default for SparseReader<'a, T, S>
+where
+    T: PartialEq + 'a,
+    S: SparseStorage<T> + 'a,
 {
     fn default() -> Self {
         Self {
@@ -83,16 +103,18 @@ where
     }
 }
 
-impl<T> FusedIterator for SparseReader<'_, T>
+impl<'a, T, S> FusedIterator for SparseReader<'a, T, S>
 where
-    T: PartialEq,
+    T: PartialEq + 'a,
+    S: SparseStorage<T> + 'a,
 {
     // nop.
 }
 
-impl<'a, T> Iterator for SparseReader<'a, T>
+impl<'a, T, S> Iterator for SparseReader<'a, T, S>
 where
-    T: PartialEq,
+    T: PartialEq + 'a,
+    S: SparseStorage<T> + 'a,
 {
     type Item = (usize, &'a T);
 
@@ -117,9 +139,10 @@ where
     }
 }
 
-impl<'a, T> DoubleEndedIterator for SparseReader<'a, T>
+impl<'a, T, S> DoubleEndedIterator for SparseReader<'a, T, S>
 where
-    T: PartialEq,
+    T: PartialEq + 'a,
+    S: SparseStorage<T> + 'a,
 {
     fn next_back(&mut self) -> Option<Self::Item> {
         if self.is_default() {
@@ -1,12 +1,14 @@
 //! Provider of [`SparseWriter`].
 
-use crate::aliases::*;
+use crate::BTreeStorage;
+use crate::Padding;
+use crate::SparseStorage;
 use crate::common::*;
 use crate::iters::*;
 use crate::prelude::*;
 use only_one::prelude::*;
 use std::fmt::Debug;
-use std::ops::{Bound, Range};
+use std::ops::Range;
 
 /// A mutable sparse iterator over the elements of a [`SparseVec`].
 ///
@@ -14,9 +16,10 @@ use std::ops::{Bound, Range};
 /// See its documentation for more.
 #[derive(Debug)]
 #[must_use = msg::iter_must_use!()]
-pub struct SparseWriter<'a, T>
+pub struct SparseWriter<'a, T, S = BTreeStorage<T>>
 where
     T: PartialEq,
+    S: SparseStorage<T> + 'a,
 {
     /// Underlying sparse vector length.
     len: usize,
@@ -24,22 +27,23 @@ where
     /// Underlying sparse vector NNP.
     nnp: usize,
 
-    /// Padding value reference.
-    padding: One<&'a T>,
+    /// Padding reference.
+    padding: One<&'a Padding<T>>,
 
     /// Slicing range.
     idx_range: Range<usize>,
 
     /// Iterating range of underlying sparse vector map.
-    map_range: One<MapRangeMut<'a, T>>,
+    map_range: One<S::RangeMut<'a>>,
 
     /// Map pointer (Used only after [`Self::map_range`] is droped.)
-    map: *mut Map<T>,
+    map: *mut S,
 }
 
-impl<'a, T> SparseWriter<'a, T>
+impl<'a, T, S> SparseWriter<'a, T, S>
 where
     T: PartialEq,
+    S: SparseStorage<T> + 'a,
 {
     /// Returns item mapper.
     ///
@@ -47,12 +51,12 @@ where
     ///
     /// ```
     /// # use sparse_vector::prelude::*;
-    /// let mut vec = SparseVec::from_iter([1, 0, 3, 0, 5]);
+    /// let mut vec = SparseVec::<_>::from_iter([1, 0, 3, 0, 5]);
     /// let mut writer = vec.sparse_writer();
     /// let map = writer.map(|x| (x.0, *x.1));
     /// assert!(map.eq([(0, 1), (2, 3), (4, 5)]));
     /// ```
-    pub fn map<B, F>(self, f: F) -> SparseWriterMap<'a, T, F>
+    pub fn map<B, F>(self, f: F) -> SparseWriterMap<'a, T, F, S>
     where
         F: FnMut((usize, &mut T)) -> B,
     {
@@ -60,12 +64,12 @@ where
     }
 
     /// Creates a new instance.
-    pub(crate) fn new(vec: &'a mut SparseVec<T>, range: Range<usize>) -> Self {
+    pub(crate) fn new(vec: &'a mut SparseVec<T, S>, range: Range<usize>) -> Self {
         let map_ptr = (&mut vec.map) as *mut _;
         Self {
             len: vec.len(),
             nnp: vec.nnp(),
-            padding: One::new(vec.padding.refs()),
+            padding: One::new(&vec.padding),
             idx_range: range.clone(),
             map_range: One::new(vec.map.range_mut(range)),
             map: map_ptr,
@@ -79,9 +83,10 @@ where
 }
 
 /// Methods like normal iterator.
-impl<'a, T> SparseWriter<'a, T>
+impl<'a, T, S> SparseWriter<'a, T, S>
 where
     T: PartialEq,
+    S: SparseStorage<T> + 'a,
 {
     /// Advances the iterator and returns the next value.
     ///
@@ -158,9 +163,10 @@ where
     }
 }
 
-impl<T> Default for SparseWriter<'_, T>
+impl<'a, T, S> Default for SparseWriter<'a, T, S>
 where
     T: PartialEq,
+    S: SparseStorage<T> + 'a,
 {
     fn default() -> Self {
         Self {
@@ -174,29 +180,22 @@ where
     }
 }
 
-impl<T> Drop for SparseWriter<'_, T>
+impl<'a, T, S> Drop for SparseWriter<'a, T, S>
 where
     T: PartialEq,
+    S: SparseStorage<T> + 'a,
 {
     fn drop(&mut self) {
         if One::exists(&self.map_range) {
-            One::take(&mut self.map_range);
+            let _ = One::take(&mut self.map_range);
         }
 
         if !self.map.is_null() {
             // TODO: 計算時間！idx_range と map_range の間だけ消せばいい。
             let map = unsafe { &mut *self.map };
-            let start = Bound::Included(&self.idx_range.start);
-            let cursor = &mut map.lower_bound_mut(start);
-            while let Some(elm) = cursor.next() {
-                if *elm.0 >= self.idx_range.end {
-                    break;
-                }
-
-                if *elm.1 == **self.padding {
-                    cursor.remove_prev();
-                }
-            }
+            let padding = &self.padding;
+            let range = self.idx_range.clone();
+            map.retain_range(range, |_, x| !padding.is_omittable(x));
         }
     }
 }
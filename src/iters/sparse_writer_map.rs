@@ -1,5 +1,7 @@
 //! Provider of [`SparseWriterMap`].
 
+use crate::BTreeStorage;
+use crate::SparseStorage;
 use crate::iters::*;
 use std::iter::FusedIterator;
 
@@ -7,29 +9,32 @@ use std::iter::FusedIterator;
 ///
 /// This type is created by [`SparseWriter::map`].
 /// See its documentation for more.
-pub struct SparseWriterMap<'a, T, F>
+pub struct SparseWriterMap<'a, T, F, S = BTreeStorage<T>>
 where
     T: PartialEq,
+    S: SparseStorage<T> + 'a,
 {
     // Base object.
-    base: SparseWriter<'a, T>,
+    base: SparseWriter<'a, T, S>,
 
     // Mapping closure.
     f: F,
 }
 
-impl<'a, T, F> SparseWriterMap<'a, T, F>
+impl<'a, T, F, S> SparseWriterMap<'a, T, F, S>
 where
     T: PartialEq,
+    S: SparseStorage<T> + 'a,
 {
-    pub(crate) fn new(base: SparseWriter<'a, T>, f: F) -> Self {
+    pub(crate) fn new(base: SparseWriter<'a, T, S>, f: F) -> Self {
         Self { base, f }
     }
 }
 
-impl<B, T, F> DoubleEndedIterator for SparseWriterMap<'_, T, F>
+impl<'a, B, T, F, S> DoubleEndedIterator for SparseWriterMap<'a, T, F, S>
 where
     T: PartialEq,
+    S: SparseStorage<T> + 'a,
     F: FnMut((usize, &mut T)) -> B,
 {
     fn next_back(&mut self) -> Option<Self::Item> {
@@ -37,25 +42,28 @@ where
     }
 }
 
-impl<B, T, F> ExactSizeIterator for SparseWriterMap<'_, T, F>
+impl<'a, B, T, F, S> ExactSizeIterator for SparseWriterMap<'a, T, F, S>
 where
     T: PartialEq,
+    S: SparseStorage<T> + 'a,
     F: FnMut((usize, &mut T)) -> B,
 {
     // nop.
 }
 
-impl<B, T, F> FusedIterator for SparseWriterMap<'_, T, F>
+impl<'a, B, T, F, S> FusedIterator for SparseWriterMap<'a, T, F, S>
 where
     T: PartialEq,
+    S: SparseStorage<T> + 'a,
     F: FnMut((usize, &mut T)) -> B,
 {
     // nop.
 }
 
-impl<B, T, F> Iterator for SparseWriterMap<'_, T, F>
+impl<'a, B, T, F, S> Iterator for SparseWriterMap<'a, T, F, S>
 where
     T: PartialEq,
+    S: SparseStorage<T> + 'a,
     F: FnMut((usize, &mut T)) -> B,
 {
     type Item = B;
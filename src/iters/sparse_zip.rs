@@ -0,0 +1,244 @@
+//! Provider of [`SparseZip`].
+
+use crate::BTreeStorage;
+use crate::SparseStorage;
+use crate::common::*;
+use crate::iters::*;
+use std::iter::{FusedIterator, Map};
+
+/// An item of [`SparseZip::into_items`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ZipItem<'a, T, U> {
+    /// Both sides are none padding.
+    Both(usize, &'a T, &'a U),
+
+    /// Only left side is none padding.
+    Left(usize, &'a T),
+
+    /// Only right side is none padding.
+    Right(usize, &'a U),
+}
+
+impl<T, U> ZipItem<'_, T, U> {
+    /// Returns index of this item.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sparse_vector::prelude::*;
+    /// let item = ZipItem::<i32, i32>::Left(3, &1);
+    /// assert_eq!(item.index(), 3);
+    /// ```
+    pub fn index(&self) -> usize {
+        match self {
+            Self::Both(i, _, _) => *i,
+            Self::Left(i, _) => *i,
+            Self::Right(i, _) => *i,
+        }
+    }
+}
+
+/// Conversion function from [`SparseZip`] item to [`ZipItem`].
+type ToZipItem<'a, T, U> = fn((usize, Option<&'a T>, Option<&'a U>)) -> ZipItem<'a, T, U>;
+
+/// A sparse zip iterator yielding [`ZipItem`].
+///
+/// This type is created by [`SparseSlice::zip_sparse`] or
+/// [`SparseZip::into_items`]. See its documentation for more.
+///
+/// [`SparseSlice::zip_sparse`]: crate::SparseSlice::zip_sparse
+pub type ZipSparse<'a, T, U, S = BTreeStorage<T>, S2 = BTreeStorage<U>> =
+    Map<SparseZip<'a, T, U, S, S2>, ToZipItem<'a, T, U>>;
+
+/// A sparse merge iterator over the elements of two slices.
+///
+/// This iterator yields `(index, left, right)` for each index where
+/// at least one side has none padding element, in index order. Side
+/// without none padding element at the index is `None`, meaning its
+/// padding value. Indexes are limited to the shorter slice length.
+///
+/// This type is created by [`SparseSlice::sparse_zip`].
+/// See its documentation for more.
+///
+/// [`SparseSlice::sparse_zip`]: crate::SparseSlice::sparse_zip
+#[derive(Debug)]
+#[must_use = msg::iter_must_use!()]
+pub struct SparseZip<'a, T, U, S = BTreeStorage<T>, S2 = BTreeStorage<U>>
+where
+    T: PartialEq,
+    U: PartialEq,
+    S: SparseStorage<T> + 'a,
+    S2: SparseStorage<U> + 'a,
+{
+    /// Left side padding.
+    l_padding: &'a T,
+
+    /// Right side padding.
+    r_padding: &'a U,
+
+    /// Left side reader.
+    l_reader: SparseReader<'a, T, S>,
+
+    /// Right side reader.
+    r_reader: SparseReader<'a, U, S2>,
+
+    /// Left side iterating head memo.
+    l_head: Option<(usize, &'a T)>,
+
+    /// Right side iterating head memo.
+    r_head: Option<(usize, &'a U)>,
+
+    /// Left side iterating tail memo.
+    l_tail: Option<(usize, &'a T)>,
+
+    /// Right side iterating tail memo.
+    r_tail: Option<(usize, &'a U)>,
+}
+
+impl<'a, T, U, S, S2> SparseZip<'a, T, U, S, S2>
+where
+    T: PartialEq,
+    U: PartialEq,
+    S: SparseStorage<T> + 'a,
+    S2: SparseStorage<U> + 'a,
+{
+    /// Creates a new instance.
+    pub(crate) fn new(
+        paddings: (&'a T, &'a U),
+        l_reader: SparseReader<'a, T, S>,
+        r_reader: SparseReader<'a, U, S2>,
+    ) -> Self {
+        Self {
+            l_padding: paddings.0,
+            r_padding: paddings.1,
+            l_reader,
+            r_reader,
+            l_head: None,
+            r_head: None,
+            l_tail: None,
+            r_tail: None,
+        }
+    }
+
+    /// Returns padding values of both sides.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sparse_vector::prelude::*;
+    /// let x = SparseVec::<_>::with_padding(3, 1);
+    /// let y = SparseVec::<_>::with_padding(3, 2);
+    /// let zip = x.sparse_zip(&y);
+    /// assert_eq!(zip.paddings(), (&1, &2));
+    /// ```
+    pub fn paddings(&self) -> (&'a T, &'a U) {
+        (self.l_padding, self.r_padding)
+    }
+
+    /// Converts into iterator of [`ZipItem`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sparse_vector::prelude::*;
+    /// let x = SparseVec::<_>::from_iter([1, 0, 0, 4, 0]);
+    /// let y = SparseVec::<_>::from_iter([0, 0, 3, 5, 0]);
+    /// let iter = &mut x.sparse_zip(&y).into_items();
+    /// assert_eq!(iter.next(), Some(ZipItem::Left(0, &1)));
+    /// assert_eq!(iter.next(), Some(ZipItem::Right(2, &3)));
+    /// assert_eq!(iter.next(), Some(ZipItem::Both(3, &4, &5)));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    pub fn into_items(self) -> ZipSparse<'a, T, U, S, S2> {
+        self.map(|item| match item {
+            (i, Some(x), Some(y)) => ZipItem::Both(i, x, y),
+            (i, Some(x), None) => ZipItem::Left(i, x),
+            (i, None, Some(y)) => ZipItem::Right(i, y),
+            (_, None, None) => unreachable!(),
+        })
+    }
+
+    /// Returns item made from both sides heads.
+    fn merge<V, W>(
+        l: &mut Option<(usize, V)>,
+        r: &mut Option<(usize, W)>,
+        pick: fn(usize, usize) -> usize,
+    ) -> Option<(usize, Option<V>, Option<W>)> {
+        let l_index = l.as_ref().map(|x| x.0);
+        let r_index = r.as_ref().map(|x| x.0);
+        let index = match (l_index, r_index) {
+            (Some(x), Some(y)) => pick(x, y),
+            (Some(x), None) => x,
+            (None, Some(y)) => y,
+            (None, None) => return None,
+        };
+
+        let l_value = l.take_if(|x| x.0 == index).map(|x| x.1);
+        let r_value = r.take_if(|x| x.0 == index).map(|x| x.1);
+        Some((index, l_value, r_value))
+    }
+}
+
+impl<'a, T, U, S, S2> FusedIterator for SparseZip<'a, T, U, S, S2>
+where
+    T: PartialEq,
+    U: PartialEq,
+    S: SparseStorage<T> + 'a,
+    S2: SparseStorage<U> + 'a,
+{
+    // nop.
+}
+
+impl<'a, T, U, S, S2> Iterator for SparseZip<'a, T, U, S, S2>
+where
+    T: PartialEq,
+    U: PartialEq,
+    S: SparseStorage<T> + 'a,
+    S2: SparseStorage<U> + 'a,
+{
+    type Item = (usize, Option<&'a T>, Option<&'a U>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.l_head.is_none() {
+            self.l_head = self.l_reader.next().or_else(|| self.l_tail.take());
+        }
+
+        if self.r_head.is_none() {
+            self.r_head = self.r_reader.next().or_else(|| self.r_tail.take());
+        }
+
+        Self::merge(&mut self.l_head, &mut self.r_head, usize::min)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let l_memo_len = self.l_head.iter().chain(&self.l_tail).count();
+        let r_memo_len = self.r_head.iter().chain(&self.r_tail).count();
+        let l_hint = self.l_reader.size_hint();
+        let r_hint = self.r_reader.size_hint();
+        let l_min = l_hint.0 + l_memo_len;
+        let r_min = r_hint.0 + r_memo_len;
+        let l_max = l_hint.1.unwrap() + l_memo_len;
+        let r_max = r_hint.1.unwrap() + r_memo_len;
+        (usize::max(l_min, r_min), Some(l_max + r_max))
+    }
+}
+
+impl<'a, T, U, S, S2> DoubleEndedIterator for SparseZip<'a, T, U, S, S2>
+where
+    T: PartialEq,
+    U: PartialEq,
+    S: SparseStorage<T> + 'a,
+    S2: SparseStorage<U> + 'a,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.l_tail.is_none() {
+            self.l_tail = self.l_reader.next_back().or_else(|| self.l_head.take());
+        }
+
+        if self.r_tail.is_none() {
+            self.r_tail = self.r_reader.next_back().or_else(|| self.r_head.take());
+        }
+
+        Self::merge(&mut self.l_tail, &mut self.r_tail, usize::max)
+    }
+}
@@ -1,24 +1,27 @@
 //! Provider of [`Splice`].
 
+use crate::BTreeStorage;
+use crate::SparseStorage;
 use crate::SparseVec;
 use crate::common::ExactSizeIter;
 use only_one::One;
 use std::fmt::Debug;
 use std::iter::FusedIterator;
-use std::ops::{Range, RangeBounds};
+use std::ops::Range;
 
 /// A splicing iterator for [`SparseVec`].
 ///
 /// This type is created by [`SparseVec::splice`].
 /// See its documentation for more.
 #[derive(Debug)]
-pub struct Splice<'a, I>
+pub struct Splice<'a, I, S = BTreeStorage<<I as Iterator>::Item>>
 where
     I: Iterator + 'a,
     I::Item: PartialEq,
+    S: SparseStorage<I::Item>,
 {
     /// Underlying sparse vector.
-    vec: &'a mut SparseVec<I::Item>,
+    vec: &'a mut SparseVec<I::Item, S>,
 
     /// Range for remove.
     range: Range<usize>,
@@ -33,12 +36,13 @@ where
     original_len: usize,
 }
 
-impl<'a, I> Splice<'a, I>
+impl<'a, I, S> Splice<'a, I, S>
 where
     I: Iterator + 'a,
     I::Item: PartialEq,
+    S: SparseStorage<I::Item>,
 {
-    pub(crate) fn new(vec: &'a mut SparseVec<I::Item>, range: Range<usize>, news: I) -> Self {
+    pub(crate) fn new(vec: &'a mut SparseVec<I::Item, S>, range: Range<usize>, news: I) -> Self {
         let edges = range.clone();
         let original_len = vec.len();
         let news = One::new(news);
@@ -52,75 +56,68 @@ where
     }
 }
 
-impl<'a, I> Drop for Splice<'a, I>
+impl<'a, I, S> Drop for Splice<'a, I, S>
 where
     I: Iterator + 'a,
     I::Item: PartialEq,
+    S: SparseStorage<I::Item>,
 {
     fn drop(&mut self) {
         let news = ExactSizeIter::new(One::take(&mut self.news));
         let diff = news.len() as isize - self.range.len() as isize;
-        let start = self.range.start_bound();
-        let cursor = self.vec.map.lower_bound_mut(start);
         self.vec.len = (self.original_len as isize + diff) as usize;
-
-        #[allow(unused_unsafe)]
-        unsafe {
-            let mut cursor = cursor.with_mutable_key();
-            while let Some(elm) = cursor.next() {
-                if *elm.0 < self.range.end {
-                    cursor.remove_prev();
-                } else {
-                    let entry = cursor.peek_prev().unwrap();
-                    let pos_ref = entry.0;
-                    *pos_ref = (*pos_ref as isize + diff) as usize;
-                }
-            }
-        }
+        self.vec.map.retain_range(self.range.clone(), |_, _| false);
+        self.vec.map.shift_keys(self.range.end, diff);
 
         for (i, item) in news.enumerate() {
-            let pos = self.range.start + i;
-            self.vec.map.insert(pos, item);
+            if !self.vec.padding.is_omittable(&item) {
+                let pos = self.range.start + i;
+                self.vec.map.insert(pos, item);
+            }
         }
     }
 }
 
-impl<'a, I> DoubleEndedIterator for Splice<'a, I>
+impl<'a, I, S> DoubleEndedIterator for Splice<'a, I, S>
 where
     I: Iterator + 'a,
     I::Item: PartialEq,
+    S: SparseStorage<I::Item>,
 {
     fn next_back(&mut self) -> Option<Self::Item> {
         if self.edges.is_empty() {
             return None;
         }
 
-        let ret = self.vec.map.remove(&(self.edges.end - 1));
+        let ret = self.vec.map.remove(self.edges.end - 1);
         self.edges.end -= 1;
         Some(ret.unwrap_or(self.vec.padding_val()))
     }
 }
 
-impl<'a, I> ExactSizeIterator for Splice<'a, I>
+impl<'a, I, S> ExactSizeIterator for Splice<'a, I, S>
 where
     I: Iterator + 'a,
     I::Item: PartialEq,
+    S: SparseStorage<I::Item>,
 {
     // nop.
 }
 
-impl<'a, I> FusedIterator for Splice<'a, I>
+impl<'a, I, S> FusedIterator for Splice<'a, I, S>
 where
     I: Iterator + 'a,
     I::Item: PartialEq,
+    S: SparseStorage<I::Item>,
 {
     // nop.
 }
 
-impl<'a, I> Iterator for Splice<'a, I>
+impl<'a, I, S> Iterator for Splice<'a, I, S>
 where
     I: Iterator + 'a,
     I::Item: PartialEq,
+    S: SparseStorage<I::Item>,
 {
     type Item = I::Item;
 
@@ -129,7 +126,7 @@ where
             return None;
         }
 
-        let ret = self.vec.map.remove(&self.edges.start);
+        let ret = self.vec.map.remove(self.edges.start);
         self.edges.start += 1;
         Some(ret.unwrap_or(self.vec.padding_val()))
     }
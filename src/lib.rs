@@ -11,19 +11,31 @@
 
 pub mod prelude;
 
+pub use dense_hash::*;
 pub use iters::*;
+pub use sparse_read::*;
 pub use sparse_slice::*;
 pub use sparse_slice_mut::*;
+pub use sparse_storage::*;
 pub use sparse_vec::*;
+pub use sparse_write::*;
+pub use storages::*;
 pub use value_editor::*;
 
 mod aliases;
 mod common;
+mod dense_hash;
 mod iters;
+mod ops;
 mod padding;
+mod sparse_read;
 mod sparse_slice;
 mod sparse_slice_mut;
+mod sparse_storage;
 mod sparse_vec;
+mod sparse_vec_macro;
+mod sparse_write;
+mod storages;
 mod value_editor;
 
 pub(crate) use padding::*;
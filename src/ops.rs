@@ -0,0 +1,268 @@
+//! Arithmetic operators for [`SparseVec`].
+//!
+//! Operators work element-wise. Output padding is the result of the
+//! operation between paddings, and only elements that are not padding
+//! in some operand are calculated one by one. Scalar operators apply
+//! the scalar to each element in the same way.
+
+use crate::SparseStorage;
+use crate::common::*;
+use crate::prelude::*;
+use std::iter::{Product, Sum};
+use std::mem;
+use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// Implements element-wise binary operator and its assign form.
+macro_rules! impl_binary_op {
+    ($Op:ident, $op:ident, $OpAssign:ident, $op_assign:ident) => {
+        impl<T, S> $Op<&SparseVec<T, S>> for &SparseVec<T, S>
+        where
+            T: PartialEq + Clone + $Op<Output = T>,
+            S: SparseStorage<T>,
+        {
+            type Output = SparseVec<T, S>;
+
+            #[track_caller]
+            fn $op(self, rhs: &SparseVec<T, S>) -> Self::Output {
+                binary(self, rhs, |x, y| x.clone().$op(y.clone()))
+            }
+        }
+
+        impl<T, S> $Op<&SparseVec<T, S>> for SparseVec<T, S>
+        where
+            T: PartialEq + Clone + $Op<Output = T>,
+            S: SparseStorage<T>,
+        {
+            type Output = SparseVec<T, S>;
+
+            #[track_caller]
+            fn $op(mut self, rhs: &SparseVec<T, S>) -> Self::Output {
+                self.$op_assign(rhs);
+                self
+            }
+        }
+
+        impl<T, S> $OpAssign<&SparseVec<T, S>> for SparseVec<T, S>
+        where
+            T: PartialEq + Clone + $Op<Output = T>,
+            S: SparseStorage<T>,
+        {
+            #[track_caller]
+            fn $op_assign(&mut self, rhs: &SparseVec<T, S>) {
+                binary_assign(self, rhs, |x, y| x.$op(y.clone()))
+            }
+        }
+    };
+}
+
+/// Implements scalar binary operator.
+macro_rules! impl_scalar_op {
+    ($Op:ident, $op:ident) => {
+        impl<T, S> $Op<T> for &SparseVec<T, S>
+        where
+            T: PartialEq + Clone + $Op<Output = T>,
+            S: SparseStorage<T>,
+        {
+            type Output = SparseVec<T, S>;
+
+            fn $op(self, rhs: T) -> Self::Output {
+                unary(self, |x| x.clone().$op(rhs.clone()))
+            }
+        }
+
+        impl<T, S> $Op<T> for SparseVec<T, S>
+        where
+            T: PartialEq + Clone + $Op<Output = T>,
+            S: SparseStorage<T>,
+        {
+            type Output = SparseVec<T, S>;
+
+            fn $op(mut self, rhs: T) -> Self::Output {
+                unary_assign(&mut self, |x| x.clone().$op(rhs.clone()));
+                self
+            }
+        }
+    };
+}
+
+impl_binary_op!(Add, add, AddAssign, add_assign);
+impl_binary_op!(Mul, mul, MulAssign, mul_assign);
+impl_binary_op!(Sub, sub, SubAssign, sub_assign);
+impl_scalar_op!(Div, div);
+impl_scalar_op!(Mul, mul);
+
+impl<T, S> Neg for &SparseVec<T, S>
+where
+    T: PartialEq + Clone + Neg<Output = T>,
+    S: SparseStorage<T>,
+{
+    type Output = SparseVec<T, S>;
+
+    fn neg(self) -> Self::Output {
+        unary(self, |x| -x.clone())
+    }
+}
+
+impl<T, S> Neg for SparseVec<T, S>
+where
+    T: PartialEq + Clone + Neg<Output = T>,
+    S: SparseStorage<T>,
+{
+    type Output = SparseVec<T, S>;
+
+    fn neg(mut self) -> Self::Output {
+        unary_assign(&mut self, |x| -x.clone());
+        self
+    }
+}
+
+/// Element-wise sum of vectors.
+///
+/// Returns an empty vector with default padding if the iterator is empty.
+///
+/// # Panics
+///
+/// Panics if vector lengths are not equal.
+impl<'a, T, S> Sum<&'a SparseVec<T, S>> for SparseVec<T, S>
+where
+    T: PartialEq + Clone + Default + Add<Output = T>,
+    S: SparseStorage<T> + Clone,
+{
+    fn sum<I: Iterator<Item = &'a SparseVec<T, S>>>(mut iter: I) -> Self {
+        let Some(first) = iter.next() else {
+            return Self::default();
+        };
+
+        let first = first.clone();
+        iter.fold(first, |acc, x| acc + x)
+    }
+}
+
+/// Element-wise product of vectors.
+///
+/// Returns an empty vector with default padding if the iterator is empty.
+///
+/// # Panics
+///
+/// Panics if vector lengths are not equal.
+impl<'a, T, S> Product<&'a SparseVec<T, S>> for SparseVec<T, S>
+where
+    T: PartialEq + Clone + Default + Mul<Output = T>,
+    S: SparseStorage<T> + Clone,
+{
+    fn product<I: Iterator<Item = &'a SparseVec<T, S>>>(mut iter: I) -> Self {
+        let Some(first) = iter.next() else {
+            return Self::default();
+        };
+
+        let first = first.clone();
+        iter.fold(first, |acc, x| acc * x)
+    }
+}
+
+/// Returns element-wise unary operation result.
+///
+/// Result padding keeps the padding predicate, maker and retaining mode
+/// of operand as far as possible (see [`crate::Padding::set_value`]).
+fn unary<T, S, F>(vec: &SparseVec<T, S>, f: F) -> SparseVec<T, S>
+where
+    T: PartialEq + Clone,
+    S: SparseStorage<T>,
+    F: Fn(&T) -> T,
+{
+    let mut padding = vec.padding.clone();
+    padding.set_value(f(vec.padding_ref()));
+    let mut ret = SparseVec::<T, S>::from_padding(vec.len, padding);
+    for (index, value) in vec.sparse_reader() {
+        let value = f(value);
+        if !ret.padding.is_omittable(&value) {
+            ret.map.insert(index, value);
+        }
+    }
+
+    ret
+}
+
+/// Applies element-wise unary operation in place.
+///
+/// Same as [`unary`], but modifies the operand.
+fn unary_assign<T, S, F>(vec: &mut SparseVec<T, S>, f: F)
+where
+    T: PartialEq + Clone,
+    S: SparseStorage<T>,
+    F: Fn(&T) -> T,
+{
+    let value = f(vec.padding_ref());
+    vec.padding.set_value(value);
+
+    let padding = &vec.padding;
+    vec.map.retain_range(0..vec.len, |_, x| {
+        *x = f(x);
+        !padding.is_omittable(x)
+    });
+}
+
+/// Returns element-wise binary operation result.
+///
+/// Result padding keeps the padding predicate, maker and retaining mode
+/// of left hand side as far as possible (see [`crate::Padding::set_value`]).
+/// Right hand side padding settings are ignored.
+#[track_caller]
+fn binary<T, S, F>(lhs: &SparseVec<T, S>, rhs: &SparseVec<T, S>, f: F) -> SparseVec<T, S>
+where
+    T: PartialEq + Clone,
+    S: SparseStorage<T>,
+    F: Fn(&T, &T) -> T,
+{
+    util::check_len_eq(lhs.len, rhs.len);
+
+    let zip = lhs.sparse_zip(rhs);
+    let (l_padding, r_padding) = zip.paddings();
+    let mut padding = lhs.padding.clone();
+    padding.set_value(f(l_padding, r_padding));
+    let mut ret = SparseVec::<T, S>::from_padding(lhs.len, padding);
+    for (index, l_value, r_value) in zip {
+        let value = f(l_value.unwrap_or(l_padding), r_value.unwrap_or(r_padding));
+        if !ret.padding.is_omittable(&value) {
+            ret.map.insert(index, value);
+        }
+    }
+
+    ret
+}
+
+/// Applies element-wise binary operation to left hand side.
+///
+/// Same as [`binary`], but modifies left hand side.
+#[track_caller]
+fn binary_assign<T, S, F>(lhs: &mut SparseVec<T, S>, rhs: &SparseVec<T, S>, f: F)
+where
+    T: PartialEq + Clone,
+    S: SparseStorage<T>,
+    F: Fn(T, &T) -> T,
+{
+    util::check_len_eq(lhs.len, rhs.len);
+
+    let r_padding = rhs.padding_ref();
+    let mut padding = lhs.padding.clone();
+    padding.set_value(f(lhs.padding_val(), r_padding));
+
+    let mut l_map = mem::take(&mut lhs.map);
+    for (index, r_value) in rhs.sparse_reader() {
+        if !l_map.contains_key(index) {
+            let value = f(lhs.padding_val(), r_value);
+            if !padding.is_omittable(&value) {
+                lhs.map.insert(index, value);
+            }
+        }
+    }
+
+    for (index, l_value) in l_map.drain_range(0..lhs.len) {
+        let value = f(l_value, rhs.map.get(index).unwrap_or(r_padding));
+        if !padding.is_omittable(&value) {
+            lhs.map.insert(index, value);
+        }
+    }
+
+    lhs.padding = padding;
+}
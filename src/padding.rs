@@ -6,8 +6,14 @@ pub struct Padding<T> {
     /// Padding value.
     value: T,
 
-    /// Clone method for padding value.
-    clone_value: fn(&T) -> T,
+    /// Maker of padding value.
+    maker: Maker<T>,
+
+    /// Predicate for values treated as padding.
+    predicate: Option<fn(&T) -> bool>,
+
+    /// Flag to retain stored values even if they are treated as padding.
+    retains: bool,
 }
 
 impl<T> Padding<T> {
@@ -16,10 +22,7 @@ impl<T> Padding<T> {
     where
         T: Default,
     {
-        Self {
-            value: Default::default(),
-            clone_value: Self::clone_default,
-        }
+        Self::by_fn(T::default(), T::default)
     }
 
     /// Create a new instance by clone mode.
@@ -29,10 +32,76 @@ impl<T> Padding<T> {
     {
         Self {
             value,
-            clone_value: T::clone,
+            maker: Maker::Clone(T::clone),
+            predicate: None,
+            retains: false,
         }
     }
 
+    /// Create a new instance by function mode.
+    ///
+    /// `f` must make values equal to `value`.
+    pub fn by_fn(value: T, f: fn() -> T) -> Self {
+        Self {
+            value,
+            maker: Maker::Fn(f),
+            predicate: None,
+            retains: false,
+        }
+    }
+
+    /// Set predicate for values treated as padding.
+    pub fn with_predicate(mut self, predicate: fn(&T) -> bool) -> Self {
+        self.predicate = Some(predicate);
+        self
+    }
+
+    /// Returns `true` if values treated as padding are retained.
+    pub fn retains(&self) -> bool {
+        self.retains
+    }
+
+    /// Set flag to retain values treated as padding.
+    pub fn set_retains(&mut self, value: bool) {
+        self.retains = value;
+    }
+
+    /// Set padding value keeping predicate and retaining flag.
+    ///
+    /// If padding value is made by function and its result is not equal
+    /// to `value`, maker is switched to clone mode. See [`try_set_value`]
+    /// for predicate handling.
+    ///
+    /// [`try_set_value`]: Self::try_set_value
+    pub fn set_value(&mut self, value: T)
+    where
+        T: PartialEq + Clone,
+    {
+        if let Err(value) = self.try_set_value(value) {
+            self.maker = Maker::Clone(T::clone);
+            self.set_value_unchecked(value);
+        }
+    }
+
+    /// Set padding value keeping predicate and retaining flag.
+    ///
+    /// Predicate is dropped if it does not treat `value` as padding.
+    /// Returns `value` as error, if padding value is made by function
+    /// and its result is not equal to `value`.
+    pub fn try_set_value(&mut self, value: T) -> Result<(), T>
+    where
+        T: PartialEq,
+    {
+        if let Maker::Fn(f) = self.maker
+            && f() != value
+        {
+            return Err(value);
+        }
+
+        self.set_value_unchecked(value);
+        Ok(())
+    }
+
     /// Returns padding value reference.
     pub fn refs(&self) -> &T {
         &self.value
@@ -40,14 +109,47 @@ impl<T> Padding<T> {
 
     /// Returns padding value.
     pub fn value(&self) -> T {
-        (self.clone_value)(&self.value)
+        match self.maker {
+            Maker::Clone(f) => f(&self.value),
+            Maker::Fn(f) => f(),
+        }
     }
 
-    /// Clone method for default value.
-    fn clone_default(_x: &T) -> T
+    /// Returns `true` if `value` is treated as padding.
+    pub fn is_padding(&self, value: &T) -> bool
     where
-        T: Default,
+        T: PartialEq,
+    {
+        match self.predicate {
+            Some(f) => f(value),
+            None => value == &self.value,
+        }
+    }
+
+    /// Returns `true` if `value` should not be stored.
+    pub fn is_omittable(&self, value: &T) -> bool
+    where
+        T: PartialEq,
     {
-        T::default()
+        !self.retains && self.is_padding(value)
+    }
+
+    /// Set padding value without maker check.
+    fn set_value_unchecked(&mut self, value: T) {
+        if self.predicate.is_some_and(|f| !f(&value)) {
+            self.predicate = None;
+        }
+
+        self.value = value;
     }
 }
+
+/// Maker of padding value.
+#[derive(Clone, Debug)]
+enum Maker<T> {
+    /// Clone from original padding value.
+    Clone(fn(&T) -> T),
+
+    /// Create by function.
+    Fn(fn() -> T),
+}
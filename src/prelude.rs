@@ -1,5 +1,9 @@
 //! Crate's prelude.
 
+pub use crate::SparseRead;
 pub use crate::SparseSlice;
 pub use crate::SparseSliceMut;
 pub use crate::SparseVec;
+pub use crate::SparseWrite;
+pub use crate::ZipItem;
+pub use crate::sparse_vec;
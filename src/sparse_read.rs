@@ -0,0 +1,240 @@
+//! Provider of [`SparseRead`].
+
+use crate::DenseHash;
+use crate::SparseStorage;
+use crate::iters::*;
+use crate::prelude::*;
+use std::cmp::Ordering;
+use std::ops::RangeBounds;
+
+/// Read access to sparse sequences.
+///
+/// This trait is implemented by [`SparseVec`], [`SparseSlice`] and
+/// [`SparseSliceMut`], so that generic algorithms can accept any of
+/// them. Custom storages plug in via [`SparseStorage`], and sparse
+/// vectors on them implement this trait as well.
+///
+/// Only [`as_slice`](Self::as_slice) is required, and other methods are
+/// implemented once on top of it. [`SparseSliceMut`] has no inherent
+/// read-only methods, so they are used via this trait.
+///
+/// # Examples
+///
+/// ```
+/// # use sparse_vector::prelude::*;
+/// fn stored_sum<S: SparseRead<Value = i32>>(s: &S) -> i32 {
+///     s.sparse_reader().map(|(_, x)| x).sum()
+/// }
+///
+/// let mut v = SparseVec::<_>::from_iter([1, 0, 2, 0, 3]);
+/// assert_eq!(stored_sum(&v), 6);
+/// assert_eq!(stored_sum(&v.slice(1..4)), 2);
+/// assert_eq!(stored_sum(&v.slice_mut(2..)), 5);
+/// ```
+pub trait SparseRead {
+    /// Element type.
+    type Value: PartialEq;
+
+    /// Storage type.
+    type Storage: SparseStorage<Self::Value>;
+
+    /// Returns a slice of all elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sparse_vector::prelude::*;
+    /// let v = &mut SparseVec::<_>::from_iter([1, 2, 3, 4, 5]);
+    /// let s = v.slice_mut(1..4);
+    /// assert_eq!(s.as_slice().to_vec(), vec![2, 3, 4]);
+    /// ```
+    fn as_slice(&self) -> SparseSlice<'_, Self::Value, Self::Storage>;
+
+    /// Returns `true` if there are no elements.
+    ///
+    /// See [`SparseSlice::is_empty`] for more.
+    #[must_use]
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns elements count.
+    ///
+    /// See [`SparseSlice::len`] for more.
+    #[must_use]
+    fn len(&self) -> usize {
+        self.as_slice().len()
+    }
+
+    /// Returns the padding reference.
+    ///
+    /// See [`SparseSlice::padding_ref`] for more.
+    #[must_use]
+    fn padding_ref(&self) -> &Self::Value {
+        self.as_slice().padding_ref()
+    }
+
+    /// Returns a vector with the same contents.
+    ///
+    /// See [`SparseSlice::to_vec`] for more.
+    #[must_use]
+    fn to_vec(&self) -> Vec<Self::Value>
+    where
+        Self::Value: Clone,
+    {
+        self.as_slice().to_vec()
+    }
+
+    /// Copies `self` into a new sparse vector.
+    ///
+    /// See [`SparseSlice::to_sparse_vec`] for more.
+    #[must_use]
+    fn to_sparse_vec(&self) -> SparseVec<Self::Value, Self::Storage>
+    where
+        Self::Value: Clone,
+    {
+        self.as_slice().to_sparse_vec()
+    }
+
+    /// Returns a slice of specified range.
+    ///
+    /// # Panics
+    ///
+    /// Panics in the following cases.
+    ///
+    /// - Range start and end is reverse order
+    /// - Range end is greater than `self` length
+    fn slice<R>(&self, range: R) -> SparseSlice<'_, Self::Value, Self::Storage>
+    where
+        R: RangeBounds<usize>,
+    {
+        self.as_slice().slice(range)
+    }
+
+    /// Returns a slice of specified range, or `None` if out of bounds.
+    ///
+    /// See [`SparseSlice::get_range`] for more.
+    fn get_range<R>(&self, range: R) -> Option<SparseSlice<'_, Self::Value, Self::Storage>>
+    where
+        R: RangeBounds<usize>,
+    {
+        self.as_slice().get_range(range)
+    }
+
+    /// Returns an iterator over all elements.
+    fn iter(&self) -> Iter<'_, Self::Value, Self::Storage> {
+        self.as_slice().iter()
+    }
+
+    /// Returns none padding elements reader.
+    ///
+    /// Indexes are relative to the start of `self`.
+    fn sparse_reader(&self) -> SparseReader<'_, Self::Value, Self::Storage> {
+        self.as_slice().sparse_reader()
+    }
+
+    /// Returns none padding elements merge iterator with other slice.
+    ///
+    /// See [`SparseSlice::sparse_zip`] for more.
+    fn sparse_zip<'b, U, S2>(
+        &'b self,
+        other: &SparseSlice<'b, U, S2>,
+    ) -> SparseZip<'b, Self::Value, U, Self::Storage, S2>
+    where
+        U: PartialEq,
+        S2: SparseStorage<U>,
+    {
+        self.as_slice().sparse_zip(other)
+    }
+
+    /// Returns none padding elements zip iterator with other slice.
+    ///
+    /// See [`SparseSlice::zip_sparse`] for more.
+    fn zip_sparse<'b, U, S2>(
+        &'b self,
+        other: &SparseSlice<'b, U, S2>,
+    ) -> ZipSparse<'b, Self::Value, U, Self::Storage, S2>
+    where
+        U: PartialEq,
+        S2: SparseStorage<U>,
+    {
+        self.as_slice().zip_sparse(other)
+    }
+
+    /// Returns hashing adapter compatible with dense slices.
+    ///
+    /// See [`SparseSlice::dense_hash`] for more.
+    fn dense_hash(&self) -> DenseHash<'_, Self::Value, Self::Storage> {
+        self.as_slice().dense_hash()
+    }
+
+    /// Returns `true` if elements are sorted in ascending order.
+    ///
+    /// See [`SparseSlice::is_sorted`] for more.
+    #[must_use]
+    fn is_sorted(&self) -> bool
+    where
+        Self::Value: PartialOrd,
+    {
+        self.as_slice().is_sorted()
+    }
+
+    /// Binary searches `self` for `value`.
+    ///
+    /// See [`SparseSlice::binary_search`] for more.
+    fn binary_search(&self, value: &Self::Value) -> Result<usize, usize>
+    where
+        Self::Value: Ord,
+    {
+        self.as_slice().binary_search(value)
+    }
+
+    /// Binary searches sorted `self` with a comparator function.
+    ///
+    /// See [`SparseSlice::binary_search_by`] for more.
+    fn binary_search_by<'s, F>(&'s self, f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&'s Self::Value) -> Ordering,
+    {
+        self.as_slice().binary_search_by(f)
+    }
+}
+
+impl<'a, T, S> SparseRead for SparseSlice<'a, T, S>
+where
+    T: PartialEq,
+    S: SparseStorage<T>,
+{
+    type Value = T;
+    type Storage = S;
+
+    fn as_slice(&self) -> SparseSlice<'_, T, S> {
+        SparseSlice::slice(self, ..)
+    }
+}
+
+impl<'a, T, S> SparseRead for SparseSliceMut<'a, T, S>
+where
+    T: PartialEq,
+    S: SparseStorage<T>,
+{
+    type Value = T;
+    type Storage = S;
+
+    fn as_slice(&self) -> SparseSlice<'_, T, S> {
+        SparseSlice::new(self.vec, self.range.clone())
+    }
+}
+
+impl<T, S> SparseRead for SparseVec<T, S>
+where
+    T: PartialEq,
+    S: SparseStorage<T>,
+{
+    type Value = T;
+    type Storage = S;
+
+    fn as_slice(&self) -> SparseSlice<'_, T, S> {
+        SparseVec::slice(self, ..)
+    }
+}
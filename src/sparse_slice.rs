@@ -1,11 +1,17 @@
 //! Provider of [`SparseSlice`].
 
+use crate::BTreeStorage;
+use crate::DenseHash;
+use crate::SparseStorage;
 use crate::common::*;
 use crate::iters::*;
 use crate::prelude::*;
 use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use std::fmt::{self, Debug, Display, Formatter};
 use std::hash::{Hash, Hasher};
-use std::ops::{Index, Range, RangeBounds};
+use std::iter::{self, Product, Sum};
+use std::ops::{Index, Mul, Range, RangeBounds};
 
 /// A slice for [`SparseVec`].
 ///
@@ -13,27 +19,27 @@ use std::ops::{Index, Range, RangeBounds};
 ///
 /// ```
 /// # use sparse_vector::prelude::*;
-/// let v = SparseVec::from_iter([0, 1, 0, 3, 0]);
+/// let v = SparseVec::<_>::from_iter([0, 1, 0, 3, 0]);
 /// let s = v.slice(1..4);
 /// assert_eq!(s[2], 3);
 /// ```
-#[repr(C)]
 #[must_use]
-#[derive(Debug, Eq)]
-pub struct SparseSlice<'a, T>
+pub struct SparseSlice<'a, T, S = BTreeStorage<T>>
 where
     T: PartialEq,
+    S: SparseStorage<T>,
 {
     /// Target sparse vector.
-    vec: &'a SparseVec<T>,
+    vec: &'a SparseVec<T, S>,
 
     /// Slicing range.
     range: Range<usize>,
 }
 
-impl<'a, T> SparseSlice<'a, T>
+impl<'a, T, S> SparseSlice<'a, T, S>
 where
     T: PartialEq,
+    S: SparseStorage<T>,
 {
     /// Returns `true` if slice is empty.
     ///
@@ -41,7 +47,7 @@ where
     ///
     /// ```
     /// # use sparse_vector::prelude::*;
-    /// let v = SparseVec::from_iter([1, 2, 3, 4, 5]);
+    /// let v = SparseVec::<_>::from_iter([1, 2, 3, 4, 5]);
     /// let s = v.slice(3..3);
     /// assert!(s.is_empty());
     ///
@@ -59,7 +65,7 @@ where
     ///
     /// ```
     /// # use sparse_vector::prelude::*;
-    /// let v = SparseVec::from_iter([1, 2, 3, 4, 5]);
+    /// let v = SparseVec::<_>::from_iter([1, 2, 3, 4, 5]);
     /// let s = v.slice(1..4);
     /// assert_eq!(s.len(), 3);
     /// ```
@@ -68,13 +74,28 @@ where
         self.range.len()
     }
 
+    /// Returns the padding reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sparse_vector::prelude::*;
+    /// let v = SparseVec::<_>::with_padding(5, 42);
+    /// let s = v.slice(1..4);
+    /// assert_eq!(s.padding_ref(), &42);
+    /// ```
+    #[must_use]
+    pub fn padding_ref(&self) -> &'a T {
+        self.vec.padding_ref()
+    }
+
     /// Returns a vector with the same contents of this slice.
     ///
     /// # Examples
     ///
     /// ```
     /// # use sparse_vector::prelude::*;
-    /// let v = SparseVec::from_iter([1, 2, 3, 4, 5]);
+    /// let v = SparseVec::<_>::from_iter([1, 2, 3, 4, 5]);
     /// let s = v.slice(1..4);
     /// assert_eq!(s.to_vec(), vec![2, 3, 4]);
     /// ```
@@ -86,6 +107,35 @@ where
         Vec::from_iter(self.iter().cloned())
     }
 
+    /// Copies `self` into a new sparse vector.
+    ///
+    /// Indexes are rebased to the start of this slice, and padding
+    /// settings of target vector are kept. This takes O(nnp) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sparse_vector::prelude::*;
+    /// let v = SparseVec::<_>::from_iter([1, 2, 0, 4, 5]);
+    /// let s = v.slice(1..4);
+    /// let w = s.to_sparse_vec();
+    /// assert_eq!(w.to_vec(), vec![2, 0, 4]);
+    /// assert_eq!(w.nnp(), 2);
+    /// ```
+    #[must_use]
+    pub fn to_sparse_vec(&self) -> SparseVec<T, S>
+    where
+        T: Clone,
+    {
+        let padding = self.vec.padding.clone();
+        let mut ret = SparseVec::<T, S>::from_padding(self.len(), padding);
+        for (index, value) in self.sparse_reader() {
+            ret.map.insert(index, value.clone());
+        }
+
+        ret
+    }
+
     /// Returns a slice of specified range.
     ///
     /// # Panics
@@ -99,12 +149,12 @@ where
     ///
     /// ```
     /// # use sparse_vector::prelude::*;
-    /// let v = SparseVec::from_iter([1, 2, 3, 4, 5, 6]);
+    /// let v = SparseVec::<_>::from_iter([1, 2, 3, 4, 5, 6]);
     /// let s1 = v.slice(1..5);
     /// let s2 = s1.slice(1..3);
     /// assert_eq!(s2.to_vec(), vec![3, 4]);
     /// ```
-    pub fn slice<R>(&self, range: R) -> SparseSlice<'_, T>
+    pub fn slice<R>(&self, range: R) -> SparseSlice<'a, T, S>
     where
         R: RangeBounds<usize>,
     {
@@ -115,13 +165,34 @@ where
         Self { vec, range }
     }
 
+    /// Returns a slice of specified range, or `None` if out of bounds.
+    ///
+    /// This is a non-panicking version of [`slice`](Self::slice).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sparse_vector::prelude::*;
+    /// let v = SparseVec::<_>::from_iter([1, 2, 3, 4, 5, 6]);
+    /// let s = v.slice(1..5);
+    /// assert_eq!(s.get_range(1..3).unwrap().to_vec(), vec![3, 4]);
+    /// assert!(s.get_range(1..5).is_none());
+    /// ```
+    pub fn get_range<R>(&self, range: R) -> Option<SparseSlice<'a, T, S>>
+    where
+        R: RangeBounds<usize>,
+    {
+        let range = util::checked_range(range, self.len())?;
+        Some(self.slice(range))
+    }
+
     /// Returns an iterator.
     ///
     /// # Examples
     ///
     /// ```
     /// # use sparse_vector::prelude::*;
-    /// let v = SparseVec::from_iter([1, 0, 2, 0, 3]);
+    /// let v = SparseVec::<_>::from_iter([1, 0, 2, 0, 3]);
     /// let s = v.slice(1..4);
     /// let iter = &mut s.iter();
     /// assert_eq!(iter.next(), Some(&0));
@@ -129,7 +200,7 @@ where
     /// assert_eq!(iter.next(), Some(&0));
     /// assert_eq!(iter.next(), None);
     /// ```
-    pub fn iter(&self) -> Iter<'_, T> {
+    pub fn iter(&self) -> Iter<'a, T, S> {
         Iter::new(self.vec, self.range.clone())
     }
 
@@ -139,69 +210,957 @@ where
     ///
     /// ```
     /// # use sparse_vector::prelude::*;
-    /// let v = SparseVec::from_iter([1, 2, 0, 4, 5]);
+    /// let v = SparseVec::<_>::from_iter([1, 2, 0, 4, 5]);
     /// let s = v.slice(1..4);
     /// let iter = &mut s.sparse_reader();
     /// assert_eq!(iter.next(), Some((0, &2)));
     /// assert_eq!(iter.next(), Some((2, &4)));
     /// assert_eq!(iter.next(), None);
     /// ```
-    pub fn sparse_reader(&self) -> SparseReader<'_, T> {
+    pub fn sparse_reader(&self) -> SparseReader<'a, T, S> {
         SparseReader::new(self.vec, self.range.clone())
     }
 
+    /// Returns none padding elements merge iterator with other slice.
+    ///
+    /// Indexes where both sides are padding are skipped. So, padding
+    /// values of both sides are available by [`SparseZip::paddings`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sparse_vector::prelude::*;
+    /// let x = SparseVec::<_>::from_iter([1, 0, 0, 4, 0]);
+    /// let y = SparseVec::<_>::from_iter([0, 0, 3, 5, 0]);
+    /// let (sx, sy) = (x.slice(..), y.slice(..));
+    /// let iter = &mut sx.sparse_zip(&sy);
+    /// assert_eq!(iter.next(), Some((0, Some(&1), None)));
+    /// assert_eq!(iter.next(), Some((2, None, Some(&3))));
+    /// assert_eq!(iter.next(), Some((3, Some(&4), Some(&5))));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    pub fn sparse_zip<'b, U, S2>(
+        &self,
+        other: &SparseSlice<'b, U, S2>,
+    ) -> SparseZip<'b, T, U, S, S2>
+    where
+        'a: 'b,
+        U: PartialEq,
+        S2: SparseStorage<U>,
+    {
+        let len = usize::min(self.len(), other.len());
+        let s_range = self.range.start..(self.range.start + len);
+        let o_range = other.range.start..(other.range.start + len);
+        let paddings = (self.vec.padding_ref(), other.vec.padding_ref());
+        let s_reader = SparseReader::new(self.vec, s_range);
+        let o_reader = SparseReader::new(other.vec, o_range);
+        SparseZip::new(paddings, s_reader, o_reader)
+    }
+
+    /// Returns none padding elements zip iterator with other slice.
+    ///
+    /// This is a shorthand of [`sparse_zip`] and [`SparseZip::into_items`].
+    ///
+    /// [`sparse_zip`]: Self::sparse_zip
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sparse_vector::prelude::*;
+    /// let x = SparseVec::<_>::from_iter([1, 0, 0, 4, 0]);
+    /// let y = SparseVec::<_>::from_iter([0, 0, 3, 5, 0]);
+    /// let (sx, sy) = (x.slice(..), y.slice(..));
+    /// let iter = &mut sx.zip_sparse(&sy);
+    /// assert_eq!(iter.next(), Some(ZipItem::Left(0, &1)));
+    /// assert_eq!(iter.next(), Some(ZipItem::Right(2, &3)));
+    /// assert_eq!(iter.next(), Some(ZipItem::Both(3, &4, &5)));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    pub fn zip_sparse<'b, U, S2>(
+        &self,
+        other: &SparseSlice<'b, U, S2>,
+    ) -> ZipSparse<'b, T, U, S, S2>
+    where
+        'a: 'b,
+        U: PartialEq,
+        S2: SparseStorage<U>,
+    {
+        self.sparse_zip(other).into_items()
+    }
+
+    /// Returns none padding elements merge iterator of some slices.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sparse_vector::prelude::*;
+    /// let x = SparseVec::<_>::from_iter([1, 0, 0, 4]);
+    /// let y = SparseVec::<_>::from_iter([0, 0, 3, 5]);
+    /// let z = SparseVec::<_>::from_iter([0, 0, 0, 6]);
+    /// let (sx, sy, sz) = (x.slice(..), y.slice(..), z.slice(..));
+    /// let iter = &mut SparseSlice::merge_sparse([&sx, &sy, &sz]);
+    /// assert_eq!(iter.next(), Some((0, [Some(&1), None, None])));
+    /// assert_eq!(iter.next(), Some((2, [None, Some(&3), None])));
+    /// assert_eq!(iter.next(), Some((3, [Some(&4), Some(&5), Some(&6)])));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    pub fn merge_sparse<const N: usize>(slices: [&Self; N]) -> MergeSparse<'a, T, N, S> {
+        let len = slices.iter().map(|x| x.len()).min().unwrap_or(0);
+        let paddings = slices.map(|x| x.vec.padding_ref());
+        let readers = slices.map(|x| {
+            let range = x.range.start..(x.range.start + len);
+            SparseReader::new(x.vec, range)
+        });
+
+        MergeSparse::new(paddings, readers)
+    }
+
+    /// Returns `true` if this slice contains an element equal to `value`.
+    ///
+    /// If `value` is equal to padding value and the whole vector has fewer
+    /// stored elements than this slice length, this finishes in O(1).
+    /// Otherwise, stored elements are scanned until the first padding
+    /// position is found.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sparse_vector::prelude::*;
+    /// let v = SparseVec::<_>::from_iter([1, 0, 2, 0, 3]);
+    /// assert!(v.slice(1..4).contains(&0));
+    /// assert!(v.slice(1..4).contains(&2));
+    /// assert!(!v.slice(1..4).contains(&3));
+    /// ```
+    #[must_use]
+    pub fn contains(&self, value: &T) -> bool {
+        let padding = self.vec.padding_ref();
+        let padding_hit = padding == value && self.has_padding();
+        padding_hit || self.sparse_reader().any(|x| x.1 == value)
+    }
+
+    /// Returns the index of the first element satisfying `pred`.
+    ///
+    /// `pred` is called once for padding value and once for each stored
+    /// value at most.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sparse_vector::prelude::*;
+    /// let v = SparseVec::<_>::from_iter([1, 0, 2, 0, 3]);
+    /// assert_eq!(v.slice(..).position(|x| *x == 0), Some(1));
+    /// assert_eq!(v.slice(..).position(|x| *x > 1), Some(2));
+    /// assert_eq!(v.slice(..).position(|x| *x > 3), None);
+    /// ```
+    #[must_use]
+    pub fn position<P>(&self, mut pred: P) -> Option<usize>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        let padding_hit = !self.is_empty() && pred(self.vec.padding_ref());
+        let mut next_index = 0;
+
+        for (index, value) in self.sparse_reader() {
+            if padding_hit && index > next_index {
+                return Some(next_index);
+            }
+
+            if pred(value) {
+                return Some(index);
+            }
+
+            next_index = index + 1;
+        }
+
+        (padding_hit && next_index < self.len()).then_some(next_index)
+    }
+
+    /// Returns the index of the last element satisfying `pred`.
+    ///
+    /// `pred` is called once for padding value and once for each stored
+    /// value at most.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sparse_vector::prelude::*;
+    /// let v = SparseVec::<_>::from_iter([1, 0, 2, 0, 3]);
+    /// assert_eq!(v.slice(..).rposition(|x| *x == 0), Some(3));
+    /// assert_eq!(v.slice(..).rposition(|x| *x < 3), Some(3));
+    /// assert_eq!(v.slice(..).rposition(|x| *x > 3), None);
+    /// ```
+    #[must_use]
+    pub fn rposition<P>(&self, mut pred: P) -> Option<usize>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        let padding_hit = !self.is_empty() && pred(self.vec.padding_ref());
+        let mut prev_end = self.len();
+
+        for (index, value) in self.sparse_reader().rev() {
+            if padding_hit && index + 1 < prev_end {
+                return Some(prev_end - 1);
+            }
+
+            if pred(value) {
+                return Some(index);
+            }
+
+            prev_end = index;
+        }
+
+        (padding_hit && prev_end > 0).then(|| prev_end - 1)
+    }
+
+    /// Returns the first stored element satisfying `pred`.
+    ///
+    /// Padding elements are not examined.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sparse_vector::prelude::*;
+    /// let v = SparseVec::<_>::from_iter([1, 0, 2, 0, 3]);
+    /// assert_eq!(v.slice(1..).find_stored(|x| *x > 1), Some((1, &2)));
+    /// assert_eq!(v.slice(1..).find_stored(|x| *x == 0), None);
+    /// ```
+    #[must_use]
+    pub fn find_stored<P>(&self, mut pred: P) -> Option<(usize, &'a T)>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        let mut reader = SparseReader::new(self.vec, self.range.clone());
+        reader.find(|x| pred(x.1))
+    }
+
+    /// Returns an iterator over the indexes of elements equal to `value`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sparse_vector::prelude::*;
+    /// let v = SparseVec::<_>::from_iter([1, 0, 2, 1, 0]);
+    /// let s = v.slice(..);
+    /// assert!(s.positions_of(&1).eq([0, 3]));
+    /// assert!(s.positions_of(&0).eq([1, 4]));
+    /// ```
+    pub fn positions_of<'b>(&self, value: &'b T) -> PositionsOf<'b, T, S>
+    where
+        'a: 'b,
+    {
+        let padding = self.vec.padding_ref();
+        let reader = SparseReader::new(self.vec, self.range.clone());
+        PositionsOf::new(value, padding, reader, self.len())
+    }
+
+    /// Returns `true` if elements are sorted in ascending order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sparse_vector::prelude::*;
+    /// let v = SparseVec::<_>::from_iter([-1, 0, 0, 2, 1]);
+    /// assert!(v.slice(..4).is_sorted());
+    /// assert!(!v.slice(..).is_sorted());
+    /// ```
+    #[must_use]
+    pub fn is_sorted(&self) -> bool
+    where
+        T: PartialOrd,
+    {
+        let step = |(sorted, prev): (bool, Option<&'a T>), value: &'a T| {
+            (sorted && prev.is_none_or(|x| x <= value), Some(value))
+        };
+
+        let result = self.fold_sparse(
+            (true, None),
+            |acc, _, value| step(acc, value),
+            |acc, padding, len| {
+                let acc = step(acc, padding);
+                if len > 1 { step(acc, padding) } else { acc }
+            },
+        );
+
+        result.0
+    }
+
+    /// Binary searches this slice for `value`.
+    ///
+    /// See [`binary_search_by`](Self::binary_search_by) for more.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sparse_vector::prelude::*;
+    /// let v = SparseVec::<_>::from_iter([-2, 0, 0, 0, 3]);
+    /// assert_eq!(v.slice(..).binary_search(&3), Ok(4));
+    /// assert_eq!(v.slice(..).binary_search(&1), Err(4));
+    /// ```
+    pub fn binary_search(&self, value: &T) -> Result<usize, usize>
+    where
+        T: Ord,
+    {
+        self.binary_search_by(|x| x.cmp(value))
+    }
+
+    /// Binary searches this sorted slice with a comparator function.
+    ///
+    /// Results are same as [`slice::binary_search_by`]. A run of padding
+    /// values is treated as a single block, so `f` is called for padding
+    /// value at most once, and each probe into a padding run skips it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sparse_vector::prelude::*;
+    /// let mut v = SparseVec::<_>::new(1_000_000);
+    /// *v.edit(0) = -1;
+    /// *v.edit(999_999) = 1;
+    /// let s = v.slice(..);
+    /// assert_eq!(s.binary_search_by(|x| x.cmp(&1)), Ok(999_999));
+    /// assert_eq!(s.binary_search_by(|x| x.cmp(&-2)), Err(0));
+    /// ```
+    pub fn binary_search_by<F>(&self, mut f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&'a T) -> Ordering,
+    {
+        let start = self.range.start;
+        let padding = self.vec.padding_ref();
+        let mut padding_ord = None;
+        let mut lo = 0;
+        let mut hi = self.len();
+
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            let next = self.vec.map.range((start + mid)..(start + hi)).next();
+            let next = next.map(|x| (*x.0 - start, x.1));
+            match next {
+                Some((index, value)) if index == mid => match f(value) {
+                    Ordering::Less => lo = mid + 1,
+                    Ordering::Greater => hi = mid,
+                    Ordering::Equal => return Ok(mid),
+                },
+                _ => match *padding_ord.get_or_insert_with(|| f(padding)) {
+                    Ordering::Less => lo = next.map_or(hi, |x| x.0),
+                    Ordering::Greater => {
+                        let prev = self.vec.map.range((start + lo)..(start + mid)).next_back();
+                        hi = prev.map_or(lo, |x| *x.0 - start + 1);
+                    }
+                    Ordering::Equal => return Ok(mid),
+                },
+            }
+        }
+
+        Err(lo)
+    }
+
+    /// Returns hashing adapter compatible with dense slices.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sparse_vector::prelude::*;
+    /// # use std::hash::{BuildHasher, RandomState};
+    /// let v = SparseVec::<_>::from_iter([1, 0, 3, 0, 5]);
+    /// let s = RandomState::new();
+    /// assert_eq!(s.hash_one(v.slice(1..4).dense_hash()), s.hash_one([0, 3, 0]));
+    /// ```
+    pub fn dense_hash(&self) -> DenseHash<'a, T, S> {
+        DenseHash::new(self.slice(..))
+    }
+
+    /// Folds elements with callbacks for stored values and padding runs.
+    ///
+    /// Elements are visited in index order. `on_value` is called with
+    /// index for each stored value, and `on_padding_run` is called with
+    /// length for each maximal run of padding values. So, long padding
+    /// runs are handled by a single call. Stored values equal to padding
+    /// value (in retaining mode) are merged into padding runs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sparse_vector::prelude::*;
+    /// let v = SparseVec::<_>::from_iter([2.0, 0.5, 1.0, 1.0, 1.0, 8.0]);
+    /// let s = v.slice(..);
+    /// let log_sum = s.fold_sparse(
+    ///     0.0,
+    ///     |acc, _, x: &f64| acc + x.ln(),
+    ///     |acc, x, n| acc + x.ln() * n as f64,
+    /// );
+    /// let geo_mean = (log_sum / s.len() as f64).exp();
+    /// assert!((geo_mean - 2f64.powf(3.0 / 6.0)).abs() < 1e-9);
+    /// ```
+    pub fn fold_sparse<B, F, G>(&self, init: B, mut on_value: F, mut on_padding_run: G) -> B
+    where
+        F: FnMut(B, usize, &'a T) -> B,
+        G: FnMut(B, &'a T, usize) -> B,
+    {
+        let padding = self.vec.padding_ref();
+        let mut acc = init;
+        let mut run_start = 0;
+
+        for (index, value) in SparseReader::new(self.vec, self.range.clone()) {
+            if value == padding {
+                continue;
+            }
+
+            if index > run_start {
+                acc = on_padding_run(acc, padding, index - run_start);
+            }
+
+            acc = on_value(acc, index, value);
+            run_start = index + 1;
+        }
+
+        if self.len() > run_start {
+            acc = on_padding_run(acc, padding, self.len() - run_start);
+        }
+
+        acc
+    }
+
+    /// Returns the number of occurrences of each value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sparse_vector::prelude::*;
+    /// let v = SparseVec::<_>::from_iter([1, 0, 2, 0, 1]);
+    /// let counts = v.slice(1..).value_counts();
+    /// assert_eq!(counts.into_iter().collect::<Vec<_>>(), [(&0, 2), (&1, 1), (&2, 1)]);
+    /// ```
+    #[must_use]
+    pub fn value_counts(&self) -> BTreeMap<&'a T, usize>
+    where
+        T: Ord,
+    {
+        self.fold_sparse(
+            BTreeMap::new(),
+            |mut acc, _, value| {
+                *acc.entry(value).or_default() += 1;
+                acc
+            },
+            |mut acc, padding, len| {
+                *acc.entry(padding).or_default() += len;
+                acc
+            },
+        )
+    }
+
+    /// Returns the number of elements equal to `value`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sparse_vector::prelude::*;
+    /// let v = SparseVec::<_>::from_iter([1, 0, 2, 0, 1]);
+    /// assert_eq!(v.slice(1..).count_value(&0), 2);
+    /// assert_eq!(v.slice(1..).count_value(&1), 1);
+    /// ```
+    #[must_use]
+    pub fn count_value(&self, value: &T) -> usize {
+        let padding_hit = self.vec.padding_ref() == value;
+        self.fold_sparse(
+            0,
+            |acc, _, x| acc + usize::from(x == value),
+            |acc, _, len| acc + if padding_hit { len } else { 0 },
+        )
+    }
+
+    /// Returns the most frequent value.
+    ///
+    /// If several values are equally most frequent, the least one is
+    /// returned. Returns `None` if this slice is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sparse_vector::prelude::*;
+    /// let v = SparseVec::<_>::from_iter([1, 2, 2, 0, 1]);
+    /// assert_eq!(v.slice(..).mode(), Some(&1));
+    /// assert_eq!(v.slice(1..).mode(), Some(&2));
+    /// ```
+    #[must_use]
+    pub fn mode(&self) -> Option<&'a T>
+    where
+        T: Ord,
+    {
+        let counts = self.value_counts().into_iter();
+        let mode = counts.reduce(|acc, x| if x.1 > acc.1 { x } else { acc });
+        mode.map(|x| x.0)
+    }
+
+    /// Returns the ratio of stored elements to all elements.
+    ///
+    /// Returns `0.0` if this slice is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sparse_vector::prelude::*;
+    /// let v = SparseVec::<_>::from_iter([1, 0, 2, 0, 0]);
+    /// assert_eq!(v.slice(..4).density(), 0.5);
+    /// ```
+    #[must_use]
+    pub fn density(&self) -> f64 {
+        if self.is_empty() {
+            return 0.0;
+        }
+
+        self.sparse_reader().count() as f64 / self.len() as f64
+    }
+
+    /// Returns the sum of all elements.
+    ///
+    /// Padding values are summed up by doubling, so this takes only
+    /// O(nnp + log n) additions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sparse_vector::prelude::*;
+    /// let v = SparseVec::<_>::with_padding(1000, 1);
+    /// assert_eq!(v.slice(1..).sum(), 999);
+    /// ```
+    #[must_use]
+    pub fn sum(&self) -> T
+    where
+        T: Clone + Sum,
+    {
+        let mut nnp = 0;
+        let reader = self.sparse_reader().inspect(|_| nnp += 1);
+        let stored = reader.map(|x| x.1.clone()).sum::<T>();
+        let padding_len = self.len() - nnp;
+        let padding = self.vec.padding_ref();
+        let paddings = util::repeat_fold(padding, padding_len, |x, y| [x, y].into_iter().sum());
+        iter::once(stored).chain(paddings).sum()
+    }
+
+    /// Returns the product of all elements.
+    ///
+    /// Padding values are multiplied by squaring, so this takes only
+    /// O(nnp + log n) multiplications.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sparse_vector::prelude::*;
+    /// let v = SparseVec::<_>::with_padding(1000, 1);
+    /// assert_eq!(v.slice(1..).product(), 1);
+    /// ```
+    #[must_use]
+    pub fn product(&self) -> T
+    where
+        T: Clone + Product,
+    {
+        let mut nnp = 0;
+        let reader = self.sparse_reader().inspect(|_| nnp += 1);
+        let stored = reader.map(|x| x.1.clone()).product::<T>();
+        let padding_len = self.len() - nnp;
+        let padding = self.vec.padding_ref();
+        let paddings = util::repeat_fold(padding, padding_len, |x, y| [x, y].into_iter().product());
+        iter::once(stored).chain(paddings).product()
+    }
+
+    /// Returns the dot product with other slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `other` length is not equal to this slice length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sparse_vector::prelude::*;
+    /// let x = SparseVec::<_>::from_iter([1, 0, 2, 0, 3]);
+    /// let y = SparseVec::<_>::from_iter([0, 0, 4, 5, 6]);
+    /// assert_eq!(x.slice(..).dot(&y.slice(..)), 26);
+    /// ```
+    #[must_use]
+    #[track_caller]
+    pub fn dot<S2>(&self, other: &SparseSlice<'_, T, S2>) -> T
+    where
+        T: Clone + Mul<Output = T> + Sum,
+        S2: SparseStorage<T>,
+    {
+        util::check_len_eq(self.len(), other.len());
+
+        let zip = self.sparse_zip(other);
+        let (s_padding, o_padding) = zip.paddings();
+        let mut count = 0;
+        let stored = zip
+            .inspect(|_| count += 1)
+            .map(|(_, x, y)| x.unwrap_or(s_padding).clone() * y.unwrap_or(o_padding).clone())
+            .sum::<T>();
+
+        let padding_len = self.len() - count;
+        let padding = s_padding.clone() * o_padding.clone();
+        let paddings = util::repeat_fold(&padding, padding_len, |x, y| [x, y].into_iter().sum());
+        iter::once(stored).chain(paddings).sum()
+    }
+
+    /// Returns the minimum element.
+    ///
+    /// If several elements are equally minimum, the first one is returned.
+    /// Values that are not comparable with themselves (such as NaN) are
+    /// ignored. Returns `None` if no element is comparable.
+    ///
+    /// This is not named `min`, since method call syntax would resolve
+    /// it to [`Ord::min`] (it takes `self` by value, so it is preferred).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sparse_vector::prelude::*;
+    /// let v = SparseVec::<_>::from_iter([1, 0, -2, 0, 3]);
+    /// assert_eq!(v.slice(..).min_value(), Some(&-2));
+    /// ```
+    #[must_use]
+    pub fn min_value(&self) -> Option<&'a T>
+    where
+        T: PartialOrd,
+    {
+        self.find_extreme(|x, y| x < y).map(|x| x.1)
+    }
+
+    /// Returns the maximum element.
+    ///
+    /// If several elements are equally maximum, the first one is returned.
+    /// Values that are not comparable with themselves (such as NaN) are
+    /// ignored. Returns `None` if no element is comparable.
+    ///
+    /// This is not named `max`, since method call syntax would resolve
+    /// it to [`Ord::max`] (it takes `self` by value, so it is preferred).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sparse_vector::prelude::*;
+    /// let v = SparseVec::<_>::from_iter([1, 0, -2, 0, 3]);
+    /// assert_eq!(v.slice(..).max_value(), Some(&3));
+    /// ```
+    #[must_use]
+    pub fn max_value(&self) -> Option<&'a T>
+    where
+        T: PartialOrd,
+    {
+        self.find_extreme(|x, y| x > y).map(|x| x.1)
+    }
+
+    /// Returns the index of the maximum element.
+    ///
+    /// Comparison rules are same as [`max_value`](Self::max_value).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sparse_vector::prelude::*;
+    /// let v = SparseVec::<_>::from_iter([-1, 0, -2, 0, -3]);
+    /// assert_eq!(v.slice(..).argmax(), Some(1));
+    /// ```
+    #[must_use]
+    pub fn argmax(&self) -> Option<usize>
+    where
+        T: PartialOrd,
+    {
+        self.find_extreme(|x, y| x > y).map(|x| x.0)
+    }
+
     /// Creates a new instance.
-    pub(crate) fn new(vec: &'a SparseVec<T>, range: Range<usize>) -> Self {
+    pub(crate) fn new(vec: &'a SparseVec<T, S>, range: Range<usize>) -> Self {
         assert!(range.end <= vec.len);
         Self { vec, range }
     }
+
+    /// Writes compact debug format with given type name.
+    pub(crate) fn fmt_debug(&self, name: &str, f: &mut Formatter<'_>) -> fmt::Result
+    where
+        T: Debug,
+    {
+        const DENSE_MAX_LEN: usize = 16;
+
+        if f.alternate() && self.len() <= DENSE_MAX_LEN {
+            write!(f, "{name} ")?;
+            return f.debug_list().entries(self.iter()).finish();
+        }
+
+        write!(f, "{name}(len={}, pad=", self.len())?;
+        Debug::fmt(self.padding_ref(), f)?;
+        f.write_str(", ")?;
+        f.debug_map().entries(self.sparse_reader()).finish()?;
+        f.write_str(")")
+    }
+
+    /// Returns `true` if this slice has some padding elements.
+    fn has_padding(&self) -> bool {
+        // Stored elements of this slice are at most all stored elements.
+        if self.vec.nnp() < self.len() {
+            return true;
+        }
+
+        let mut next_index = 0;
+        for (index, _) in self.sparse_reader() {
+            if index > next_index {
+                break;
+            }
+
+            next_index = index + 1;
+        }
+
+        next_index < self.len()
+    }
+
+    /// Returns the first element which no other element beats.
+    fn find_extreme(&self, beats: fn(&T, &T) -> bool) -> Option<(usize, &'a T)>
+    where
+        T: PartialOrd,
+    {
+        let padding = self.vec.padding_ref();
+        let mut ret = None as Option<(usize, &'a T)>;
+        let mut padding_found = false;
+        let mut next_index = 0;
+        let mut update = |index, value: &'a T| {
+            let comparable = value.partial_cmp(value).is_some();
+            if comparable && ret.is_none_or(|x| beats(value, x.1)) {
+                ret = Some((index, value));
+            }
+        };
+
+        for (index, value) in SparseReader::new(self.vec, self.range.clone()) {
+            if !padding_found && index > next_index {
+                update(next_index, padding);
+                padding_found = true;
+            }
+
+            update(index, value);
+            next_index = index + 1;
+        }
+
+        if !padding_found && next_index < self.len() {
+            update(next_index, padding);
+        }
+
+        ret
+    }
+
+    /// Compares lexicographically with element comparison function.
+    fn compare_by<F>(&self, other: &Self, mut f: F) -> Option<Ordering>
+    where
+        F: FnMut(&T, &T) -> Option<Ordering>,
+    {
+        let len = usize::min(self.len(), other.len());
+        let zip = self.sparse_zip(other);
+        let (s_padding, o_padding) = zip.paddings();
+        let mut paddings_cmp = None;
+        let mut next_index = 0;
+
+        for (index, s_value, o_value) in zip {
+            if index > next_index {
+                match *paddings_cmp.get_or_insert_with(|| f(s_padding, o_padding)) {
+                    Some(Ordering::Equal) => {}
+                    x => return x,
+                }
+            }
+
+            match f(s_value.unwrap_or(s_padding), o_value.unwrap_or(o_padding)) {
+                Some(Ordering::Equal) => {}
+                x => return x,
+            }
+
+            next_index = index + 1;
+        }
+
+        if len > next_index {
+            match *paddings_cmp.get_or_insert_with(|| f(s_padding, o_padding)) {
+                Some(Ordering::Equal) => {}
+                x => return x,
+            }
+        }
+
+        self.len().partial_cmp(&other.len())
+    }
 }
 
-impl<'a, T> Hash for SparseSlice<'a, T>
+/// Implements norms for floating point slice.
+macro_rules! impl_norms {
+    ($t:ty) => {
+        impl<'a, S> SparseSlice<'a, $t, S>
+        where
+            S: SparseStorage<$t>,
+        {
+            /// Returns the L1 norm, sum of absolute values.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// # use sparse_vector::prelude::*;
+            #[doc = concat!("let v = SparseVec::<", stringify!($t), ">::from_iter([3.0, 0.0, -4.0]);")]
+            /// assert_eq!(v.slice(..).norm_l1(), 7.0);
+            /// ```
+            #[must_use]
+            pub fn norm_l1(&self) -> $t {
+                self.fold_sparse(
+                    0.0,
+                    |acc, _, x| acc + x.abs(),
+                    |acc, x, n| acc + x.abs() * n as $t,
+                )
+            }
+
+            /// Returns the L2 norm, square root of sum of squares.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// # use sparse_vector::prelude::*;
+            #[doc = concat!("let v = SparseVec::<", stringify!($t), ">::from_iter([3.0, 0.0, -4.0]);")]
+            /// assert_eq!(v.slice(..).norm_l2(), 5.0);
+            /// ```
+            #[must_use]
+            pub fn norm_l2(&self) -> $t {
+                let sum = self.fold_sparse(
+                    0.0,
+                    |acc, _, x| acc + x * x,
+                    |acc, x, n| acc + x * x * n as $t,
+                );
+                sum.sqrt()
+            }
+
+            /// Returns the L-infinity norm, maximum of absolute values.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// # use sparse_vector::prelude::*;
+            #[doc = concat!("let v = SparseVec::<", stringify!($t), ">::from_iter([3.0, 0.0, -4.0]);")]
+            /// assert_eq!(v.slice(..).norm_inf(), 4.0);
+            /// ```
+            #[must_use]
+            pub fn norm_inf(&self) -> $t {
+                self.fold_sparse(
+                    0.0,
+                    |acc, _, x| acc.max(x.abs()),
+                    |acc, x, _| acc.max(x.abs()),
+                )
+            }
+        }
+    };
+}
+
+impl_norms!(f32);
+impl_norms!(f64);
+
+/// Compact debug formatting.
+///
+/// Same as [`SparseVec`]'s implementation, but only elements in
+/// slice range are shown, like `SparseSlice(len=10, pad=0, {3: 1})`.
+impl<'a, T, S> Debug for SparseSlice<'a, T, S>
 where
-    T: PartialEq + Hash,
+    T: PartialEq + Debug,
+    S: SparseStorage<T>,
 {
-    fn hash<H: Hasher>(&self, state: &mut H) {
-        let len = self.range.len();
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.fmt_debug("SparseSlice", f)
+    }
+}
+
+/// Dense formatting with elision of long padding runs.
+///
+/// Elements are shown like slice, but long padding runs are elided,
+/// like `[1, 0, …(999997 padding)…, 2]`. Format options are applied
+/// to each element.
+impl<'a, T, S> Display for SparseSlice<'a, T, S>
+where
+    T: PartialEq + Display,
+    S: SparseStorage<T>,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        const ELISION_MIN_LEN: usize = 4;
+
         let padding = self.vec.padding_ref();
-        let mut last_index = None;
-        let mut last_value = None;
-        let mut value_len = 0usize;
-
-        for elm in self.sparse_reader() {
-            let next_index = last_index.map_or(0, |x| x + 1);
-            let padding_len = elm.0 - next_index;
-            let value_changed = padding_len > 0 || Some(elm.1) != last_value;
-
-            if value_changed {
-                if value_len > 0 {
-                    (last_value.unwrap(), value_len).hash(state);
-                }
-                if padding_len > 0 {
-                    (padding, padding_len).hash(state);
+        let mut next_index = 0;
+        let mut is_first = true;
+        let mut write_value = |f: &mut Formatter<'_>, value: &T| {
+            f.write_str(if is_first { "" } else { ", " })?;
+            is_first = false;
+            Display::fmt(value, f)
+        };
+
+        f.write_str("[")?;
+
+        let elms = self.sparse_reader().map(Some).chain([None]);
+        for elm in elms {
+            let index = elm.map_or(self.len(), |x| x.0);
+            let padding_len = index - next_index;
+            if padding_len >= ELISION_MIN_LEN {
+                write_value(f, padding)?;
+                write!(f, ", …({} padding)…", padding_len - 1)?;
+            } else {
+                for _ in 0..padding_len {
+                    write_value(f, padding)?;
                 }
             }
 
-            last_index = Some(elm.0);
-            last_value = Some(elm.1);
-            value_len = if value_changed { 1 } else { value_len + 1 };
+            if let Some((_, value)) = elm {
+                write_value(f, value)?;
+            }
+
+            next_index = index + 1;
         }
 
-        let padding_len = len - last_index.map_or(0, |x| x + 1);
+        f.write_str("]")
+    }
+}
 
-        if value_len > 0 {
-            (last_value.unwrap(), value_len).hash(state);
-        }
+impl<'a, T, S> Eq for SparseSlice<'a, T, S>
+where
+    T: Eq,
+    S: SparseStorage<T>,
+{
+    // nop.
+}
 
-        if padding_len > 0 {
-            (padding, padding_len).hash(state);
-        }
+/// Run-length hashing.
+///
+/// Each maximal run of equal values is hashed as `(value, length)`. So,
+/// slices equal by [`PartialEq`] have same hash, even if their padding
+/// values or retaining modes differ.
+impl<'a, T, S> Hash for SparseSlice<'a, T, S>
+where
+    T: PartialEq + Hash,
+    S: SparseStorage<T>,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let flush = |run: Option<(&T, usize)>, state: &mut H| {
+            if let Some(run) = run {
+                run.hash(state);
+            }
+        };
+
+        let (run, state) = self.fold_sparse(
+            (None, state),
+            |(run, state), _, value| match run {
+                Some((x, n)) if x == value => (Some((x, n + 1)), state),
+                _ => {
+                    flush(run, state);
+                    (Some((value, 1)), state)
+                }
+            },
+            |(run, state), padding, len| {
+                flush(run, state);
+                (padding, len).hash(state);
+                (None, state)
+            },
+        );
+
+        flush(run, state);
     }
 }
 
-impl<'a, T> Index<usize> for SparseSlice<'a, T>
+impl<'a, T, S> Index<usize> for SparseSlice<'a, T, S>
 where
     T: PartialEq,
+    S: SparseStorage<T>,
 {
     type Output = T;
 
@@ -211,144 +1170,183 @@ where
     }
 }
 
-impl<'a, T> IntoIterator for &'a SparseSlice<'a, T>
+impl<'a, T, S> IntoIterator for &'a SparseSlice<'a, T, S>
 where
     T: PartialEq,
+    S: SparseStorage<T>,
 {
     type Item = &'a T;
-    type IntoIter = Iter<'a, T>;
+    type IntoIter = Iter<'a, T, S>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
 
-impl<'a, T> Ord for SparseSlice<'a, T>
+impl<'a, T, S> Ord for SparseSlice<'a, T, S>
 where
     T: Ord,
+    S: SparseStorage<T>,
 {
     fn cmp(&self, other: &Self) -> Ordering {
-        self.partial_cmp(other).unwrap()
+        self.compare_by(other, |x, y| Some(x.cmp(y))).unwrap()
     }
 }
 
-impl<'a, T> PartialEq for SparseSlice<'a, T>
+impl<'a, 'b, T, U, S, S2> PartialEq<SparseSlice<'b, U, S2>> for SparseSlice<'a, T, S>
 where
-    T: PartialEq,
+    T: PartialEq + PartialEq<U>,
+    U: PartialEq,
+    S: SparseStorage<T>,
+    S2: SparseStorage<U>,
 {
-    fn eq(&self, other: &Self) -> bool {
+    fn eq(&self, other: &SparseSlice<'b, U, S2>) -> bool {
         if self.range.len() != other.range.len() {
             return false;
         }
 
-        // Prepare common values.
         let len = self.range.len();
-        let s_padding = self.vec.padding_ref();
-        let o_padding = other.vec.padding_ref();
-
-        // Prepare loop variables.
-        let mut i = 0;
-        let mut s_reader = self.sparse_reader();
-        let mut o_reader = other.sparse_reader();
-        let mut s_memo = None as Option<(usize, &T)>;
-        let mut o_memo = None as Option<(usize, &T)>;
-
-        // Loop shared part.
-        while i < len {
-            // Update memos for index.
-            let s_fresh = s_memo.as_ref().is_some_and(|x| x.0 >= i);
-            let o_fresh = o_memo.as_ref().is_some_and(|x| x.0 >= i);
-            s_memo = if s_fresh { s_memo } else { s_reader.next() };
-            o_memo = if o_fresh { o_memo } else { o_reader.next() };
-
-            // Update indexs.
-            let s_index = s_memo.as_ref().map(|x| x.0).unwrap_or(len);
-            let o_index = o_memo.as_ref().map(|x| x.0).unwrap_or(len);
-            let c_index = usize::min(s_index, o_index);
-            let s_hit = c_index == s_index;
-            let o_hit = c_index == o_index;
-
-            // Compare skipped paddings.
-            if c_index > i && !PartialEq::eq(s_padding, o_padding) {
+        let zip = self.sparse_zip(other);
+        let (s_padding, o_padding) = zip.paddings();
+        let mut paddings_eq = None;
+        let mut next_index = 0;
+
+        for (index, s_value, o_value) in zip {
+            if index > next_index && !*paddings_eq.get_or_insert_with(|| s_padding == o_padding) {
                 return false;
             }
 
-            // Update values.
-            let s_value = s_memo.as_ref().map(|x| x.1).unwrap_or(s_padding);
-            let o_value = o_memo.as_ref().map(|x| x.1).unwrap_or(o_padding);
-            let s_value = if s_hit { s_value } else { s_padding };
-            let o_value = if o_hit { o_value } else { o_padding };
-
-            // Compare values.
-            if !PartialEq::eq(s_value, o_value) {
+            if s_value.unwrap_or(s_padding) != o_value.unwrap_or(o_padding) {
                 return false;
             }
 
-            i = c_index + 1;
+            next_index = index + 1;
         }
 
-        true
+        len == next_index || *paddings_eq.get_or_insert_with(|| s_padding == o_padding)
     }
 }
 
-impl<'a, T> PartialOrd for SparseSlice<'a, T>
+impl<'a, 'b, T, U, S, S2> PartialEq<SparseSliceMut<'b, U, S2>> for SparseSlice<'a, T, S>
+where
+    T: PartialEq + PartialEq<U>,
+    U: PartialEq,
+    S: SparseStorage<T>,
+    S2: SparseStorage<U>,
+{
+    fn eq(&self, other: &SparseSliceMut<'b, U, S2>) -> bool {
+        self.eq(&other.as_slice())
+    }
+}
+
+impl<'a, T, U, S, S2> PartialEq<SparseVec<U, S2>> for SparseSlice<'a, T, S>
+where
+    T: PartialEq + PartialEq<U>,
+    U: PartialEq,
+    S: SparseStorage<T>,
+    S2: SparseStorage<U>,
+{
+    fn eq(&self, other: &SparseVec<U, S2>) -> bool {
+        self.eq(&other.slice(..))
+    }
+}
+
+impl<'a, T, U, S> PartialEq<[U]> for SparseSlice<'a, T, S>
+where
+    T: PartialEq + PartialEq<U>,
+    S: SparseStorage<T>,
+{
+    fn eq(&self, other: &[U]) -> bool {
+        self.len() == other.len() && self.iter().zip(other).all(|(x, y)| x == y)
+    }
+}
+
+impl<'a, T, U, S> PartialEq<Vec<U>> for SparseSlice<'a, T, S>
+where
+    T: PartialEq + PartialEq<U>,
+    S: SparseStorage<T>,
+{
+    fn eq(&self, other: &Vec<U>) -> bool {
+        self.eq(other.as_slice())
+    }
+}
+
+impl<'a, T, U, S, const N: usize> PartialEq<[U; N]> for SparseSlice<'a, T, S>
+where
+    T: PartialEq + PartialEq<U>,
+    S: SparseStorage<T>,
+{
+    fn eq(&self, other: &[U; N]) -> bool {
+        self.eq(other.as_slice())
+    }
+}
+
+impl<'a, T, S> PartialOrd for SparseSlice<'a, T, S>
 where
     T: PartialOrd,
+    S: SparseStorage<T>,
 {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        // Prepare common values.
-        let s_padding = self.vec.padding_ref();
-        let o_padding = other.vec.padding_ref();
-        let s_len = self.range.len();
-        let o_len = other.range.len();
-        let len = usize::min(s_len, o_len);
-        let cmp_len = PartialOrd::partial_cmp(&s_len, &o_len);
-
-        // Prepare loop variables.
-        let mut i = 0;
-        let mut s_reader = self.sparse_reader();
-        let mut o_reader = other.sparse_reader();
-        let mut s_memo = None as Option<(usize, &T)>;
-        let mut o_memo = None as Option<(usize, &T)>;
-
-        // Loop shared part.
-        while i < len {
-            // Update memos for index.
-            let s_fresh = s_memo.as_ref().is_some_and(|x| x.0 >= i);
-            let o_fresh = o_memo.as_ref().is_some_and(|x| x.0 >= i);
-            s_memo = if s_fresh { s_memo } else { s_reader.next() };
-            o_memo = if o_fresh { o_memo } else { o_reader.next() };
-
-            // Update indexs.
-            let s_index = s_memo.as_ref().map(|x| x.0).unwrap_or(len);
-            let o_index = o_memo.as_ref().map(|x| x.0).unwrap_or(len);
-            let c_index = usize::min(s_index, o_index);
-            let s_hit = c_index == s_index;
-            let o_hit = c_index == o_index;
-
-            // Compare skipped paddings.
-            if c_index > i {
-                match PartialOrd::partial_cmp(s_padding, o_padding) {
-                    Some(Ordering::Equal) => {}
-                    x => return x,
-                }
-            }
+        self.compare_by(other, T::partial_cmp)
+    }
+}
 
-            // Update values.
-            let s_value = s_memo.as_ref().map(|x| x.1).unwrap_or(s_padding);
-            let o_value = o_memo.as_ref().map(|x| x.1).unwrap_or(o_padding);
-            let s_value = if s_hit { s_value } else { s_padding };
-            let o_value = if o_hit { o_value } else { o_padding };
+impl<'a, 'b, T, S> PartialOrd<SparseSliceMut<'b, T, S>> for SparseSlice<'a, T, S>
+where
+    T: PartialOrd,
+    S: SparseStorage<T>,
+{
+    fn partial_cmp(&self, other: &SparseSliceMut<'b, T, S>) -> Option<Ordering> {
+        self.partial_cmp(&other.as_slice())
+    }
+}
 
-            // Compare values.
-            match PartialOrd::partial_cmp(s_value, o_value) {
-                Some(Ordering::Equal) => {}
-                x => return x,
-            }
+impl<'a, T, S> PartialOrd<SparseVec<T, S>> for SparseSlice<'a, T, S>
+where
+    T: PartialOrd,
+    S: SparseStorage<T>,
+{
+    fn partial_cmp(&self, other: &SparseVec<T, S>) -> Option<Ordering> {
+        self.partial_cmp(&other.slice(..))
+    }
+}
 
-            i = c_index + 1;
-        }
+impl<'a, T, S> PartialOrd<[T]> for SparseSlice<'a, T, S>
+where
+    T: PartialOrd,
+    S: SparseStorage<T>,
+{
+    fn partial_cmp(&self, other: &[T]) -> Option<Ordering> {
+        self.iter().partial_cmp(other)
+    }
+}
+
+impl<'a, T, S> PartialOrd<Vec<T>> for SparseSlice<'a, T, S>
+where
+    T: PartialOrd,
+    S: SparseStorage<T>,
+{
+    fn partial_cmp(&self, other: &Vec<T>) -> Option<Ordering> {
+        self.partial_cmp(other.as_slice())
+    }
+}
 
-        cmp_len
+impl<'a, T, S, const N: usize> PartialOrd<[T; N]> for SparseSlice<'a, T, S>
+where
+    T: PartialOrd,
+    S: SparseStorage<T>,
+{
+    fn partial_cmp(&self, other: &[T; N]) -> Option<Ordering> {
+        self.partial_cmp(other.as_slice())
+    }
+}
+
+impl<'a, T, S> From<SparseSlice<'a, T, S>> for SparseVec<T, S>
+where
+    T: PartialEq + Clone,
+    S: SparseStorage<T>,
+{
+    fn from(value: SparseSlice<'a, T, S>) -> Self {
+        value.to_sparse_vec()
     }
 }
@@ -1,371 +1,690 @@
 //! Provider of [`SparseSliceMut`].
 
+use crate::BTreeStorage;
+use crate::SparseStorage;
 use crate::ValueEditor;
 use crate::common::util;
 use crate::iters::*;
 use crate::prelude::*;
 use std::cmp::Ordering;
+use std::fmt::{self, Debug, Display, Formatter};
 use std::hash::{Hash, Hasher};
-use std::mem;
-use std::ops::{Index, Range, RangeBounds};
+use std::ops::{Index, Range};
 
 /// A mutable slice for [`SparseVec`].
 ///
+/// Read-only methods (such as `len`, `iter` and `slice`) are provided by
+/// [`SparseRead`].
+///
 /// # Examples
 ///
 /// ```
 /// # use sparse_vector::prelude::*;
-/// let v = &mut SparseVec::from_iter([0, 1, 0, 3, 0, 5]);
+/// let v = &mut SparseVec::<_>::from_iter([0, 1, 0, 3, 0, 5]);
 /// let s = &mut v.slice_mut(1..4);
 /// let w = &mut s.sparse_writer();
 /// while let Some((_idx, val)) = w.next() {
 ///     *val += 1;
 /// }
 /// ```
-#[repr(C)]
 #[must_use]
-#[derive(Debug, Eq)]
-pub struct SparseSliceMut<'a, T>
+pub struct SparseSliceMut<'a, T, S = BTreeStorage<T>>
 where
     T: PartialEq,
+    S: SparseStorage<T>,
 {
     /// Target sparse vector.
-    vec: &'a mut SparseVec<T>,
+    pub(crate) vec: &'a mut SparseVec<T, S>,
 
     /// Slicing range.
-    range: Range<usize>,
+    pub(crate) range: Range<usize>,
 }
 
-impl<'a, T> SparseSliceMut<'a, T>
+impl<'a, T, S> SparseSliceMut<'a, T, S>
 where
     T: PartialEq,
+    S: SparseStorage<T>,
 {
-    /// Returns `true` if slice is empty.
+    /// Returns none padding elements writer.
     ///
     /// # Examples
     ///
     /// ```
     /// # use sparse_vector::prelude::*;
-    /// let v = &mut SparseVec::from_iter([1, 2, 3, 4, 5]);
-    /// let s = v.slice_mut(3..3);
-    /// assert!(s.is_empty());
+    /// let v = &mut SparseVec::<_>::from_iter([1, 0, 3, 0, 5, 0, 7]);
+    /// let s = &mut v.slice_mut(1..5);
+    /// {
+    ///     let w = &mut s.sparse_writer();
+    ///     while let Some((_idx, val)) = w.next() {
+    ///         *val += 1;
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(v.to_vec(), vec![1, 0, 4, 0, 6, 0, 7]);
+    /// ```
+    pub fn sparse_writer(&mut self) -> SparseWriter<'_, T, S> {
+        SparseWriter::new(self.vec, self.range.clone())
+    }
+
+    /// Takes the value of index, leaving padding value.
     ///
-    /// let s = v.slice_mut(1..4);
-    /// assert!(!s.is_empty());
+    /// # Panics
+    ///
+    /// Panics if `index` is not less than vector length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sparse_vector::prelude::*;
+    /// let v = &mut SparseVec::<_>::from_iter([1, 2, 3, 4, 5]);
+    /// let s = &mut v.slice_mut(1..4);
+    /// let mut r = s.take(1);
+    /// assert_eq!(r, 3);
+    /// assert_eq!(v.to_vec(), vec![1, 2, 0, 4, 5]);
     /// ```
-    #[must_use]
-    pub fn is_empty(&self) -> bool {
-        self.len() == 0
+    pub fn take(&mut self, index: usize) -> T {
+        assert!(index < self.range.len());
+        let removed = self.vec.map.remove(self.range.start + index);
+        removed.unwrap_or(self.vec.padding_val())
     }
 
-    /// Returns slice length.
+    /// Returns value editor.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not less than vector length.
     ///
     /// # Examples
     ///
     /// ```
     /// # use sparse_vector::prelude::*;
-    /// let v = &mut SparseVec::from_iter([1, 2, 3, 4, 5]);
-    /// let s = v.slice_mut(1..4);
-    /// assert_eq!(s.len(), 3);
+    /// let v = &mut SparseVec::<_>::from_iter([1, 2, 3, 4, 5]);
+    /// let s = &mut v.slice_mut(1..4);
+    /// *s.edit(1) = 42;
+    /// assert_eq!(v.to_vec(), vec![1, 2, 42, 4, 5]);
     /// ```
-    #[must_use]
-    pub fn len(&self) -> usize {
-        self.slice_ref().len()
+    pub fn edit(&mut self, index: usize) -> ValueEditor<'_, T, S> {
+        assert!(index < self.range.len());
+        ValueEditor::new(self.vec, self.range.start + index)
     }
 
-    /// Returns a vector with the same contents of this slice.
+    /// Fills `self` with elements by cloning `value`.
     ///
     /// # Examples
     ///
     /// ```
     /// # use sparse_vector::prelude::*;
-    /// let v = &mut SparseVec::from_iter([1, 2, 3, 4, 5]);
-    /// let s = v.slice_mut(1..4);
-    /// assert_eq!(s.to_vec(), vec![2, 3, 4]);
+    /// let v = &mut SparseVec::<_>::from_iter([1, 2, 3, 4, 5]);
+    /// let s = &mut v.slice_mut(1..4);
+    /// s.fill(42);
+    /// assert_eq!(v.to_vec(), vec![1, 42, 42, 42, 5]);
     /// ```
-    #[must_use]
-    pub fn to_vec(&self) -> Vec<T>
+    pub fn fill(&mut self, value: T)
     where
         T: Clone,
     {
-        self.slice_ref().to_vec()
+        self.fill_with(|| value.clone());
     }
 
-    /// Returns slice reference.
+    /// Fills `self` with elements returned by calling a closure repeatedly.
     ///
     /// # Examples
     ///
     /// ```
     /// # use sparse_vector::prelude::*;
-    /// let v = &mut SparseVec::from_iter([1, 2, 3, 4, 5]);
-    /// let s = v.slice_mut(1..4);
-    /// let s = s.slice_ref();
-    /// assert_eq!(s.to_vec(), vec![2, 3, 4]);
+    /// let v = &mut SparseVec::<_>::from_iter([1, 2, 3, 4, 5]);
+    /// let s = &mut v.slice_mut(1..4);
+    /// s.fill_with(|| 42);
+    /// assert_eq!(v.to_vec(), vec![1, 42, 42, 42, 5]);
     /// ```
-    pub fn slice_ref(&self) -> &SparseSlice<'_, T> {
-        unsafe { mem::transmute(self) }
+    pub fn fill_with<F>(&mut self, mut f: F)
+    where
+        F: FnMut() -> T,
+    {
+        for i in 0..self.len() {
+            let value = f();
+            *self.edit(i) = value;
+        }
     }
 
-    /// Returns a slice of specified range.
+    /// Swaps two elements.
     ///
     /// # Panics
     ///
-    /// Panics in the following cases.
+    /// Panics if `a` or `b` are out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sparse_vector::prelude::*;
+    /// let v = &mut SparseVec::<_>::from_iter(["a", "b", "c", "d", "e"]);
+    /// let s = &mut v.slice_mut(1..4);
+    /// s.swap(0, 2);
+    /// assert_eq!(v.to_vec(), vec!["a", "d", "c", "b", "e"]);
+    /// ```
+    pub fn swap(&mut self, x: usize, y: usize) {
+        assert!(x < self.len());
+        assert!(y < self.len());
+
+        if x != y {
+            let x = self.range.start + x;
+            let y = self.range.start + y;
+            let xv = self.vec.map.remove(x);
+            let yv = self.vec.map.remove(y);
+
+            if let Some(xv) = xv {
+                self.vec.map.insert(y, xv);
+            }
+
+            if let Some(yv) = yv {
+                self.vec.map.insert(x, yv);
+            }
+        }
+    }
+
+    /// Copies elements from a sparse slice into `self`.
+    ///
+    /// If padding value of `src` is treated as padding in `self`, only
+    /// stored entries of `src` are cloned, in O(nnp log nnp) time.
+    /// Otherwise, padding elements of `src` are stored explicitly.
+    ///
+    /// # Panics
     ///
-    /// - Range start and end is reverse order
-    /// - Range end is greater than this slice length
+    /// Panics if the two slices have different lengths.
     ///
     /// # Examples
     ///
     /// ```
     /// # use sparse_vector::prelude::*;
-    /// let v = &mut SparseVec::from_iter([1, 2, 3, 4, 5, 6]);
-    /// let s1 = v.slice_mut(1..5);
-    /// let s2 = s1.slice(1..3);
-    /// assert_eq!(s2.to_vec(), vec![3, 4]);
+    /// let src = SparseVec::<_>::from_iter([0, 7, 0, 9]);
+    /// let v = &mut SparseVec::<_>::from_iter([1, 2, 3, 4, 5]);
+    /// v.slice_mut(1..).clone_from_sparse(&src.slice(..));
+    /// assert_eq!(v.to_vec(), vec![1, 0, 7, 0, 9]);
     /// ```
-    pub fn slice<R>(&self, range: R) -> SparseSlice<'_, T>
+    #[track_caller]
+    pub fn clone_from_sparse(&mut self, src: &SparseSlice<'_, T, S>)
     where
-        R: RangeBounds<usize>,
+        T: Clone,
     {
-        let len = self.range.len();
-        let range = util::normalize_range(range, len);
-        self.slice_ref().slice(range)
+        util::check_len_eq(self.len(), src.len());
+
+        let _ = self.take_stored();
+        if self.vec.padding.is_padding(src.padding_ref()) {
+            self.put_stored(src.sparse_reader().map(|(i, x)| (i, x.clone())));
+        } else {
+            self.put_stored(src.iter().cloned().enumerate());
+        }
     }
 
-    /// Returns an iterator.
+    /// Copies elements from a dense slice into `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the two slices have different lengths.
     ///
     /// # Examples
     ///
     /// ```
     /// # use sparse_vector::prelude::*;
-    /// let v = &mut SparseVec::from_iter([1, 0, 2, 0, 3]);
-    /// let s = v.slice_mut(1..4);
-    /// let iter = &mut s.iter();
-    /// assert_eq!(iter.next(), Some(&0));
-    /// assert_eq!(iter.next(), Some(&2));
-    /// assert_eq!(iter.next(), Some(&0));
-    /// assert_eq!(iter.next(), None);
+    /// let v = &mut SparseVec::<_>::from_iter([1, 2, 3, 4, 5]);
+    /// v.slice_mut(1..4).clone_from_slice(&[0, 7, 0]);
+    /// assert_eq!(v.to_vec(), vec![1, 0, 7, 0, 5]);
+    /// assert_eq!(v.nnp(), 3);
     /// ```
-    pub fn iter(&self) -> Iter<'_, T> {
-        self.slice_ref().iter()
+    #[track_caller]
+    pub fn clone_from_slice(&mut self, src: &[T])
+    where
+        T: Clone,
+    {
+        util::check_len_eq(self.len(), src.len());
+
+        let _ = self.take_stored();
+        self.put_stored(src.iter().cloned().enumerate());
     }
 
-    /// Returns none padding elements reader.
+    /// Copies all elements from a dense slice into `self`.
+    ///
+    /// See [`clone_from_slice`](Self::clone_from_slice) for more.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the two slices have different lengths.
     ///
     /// # Examples
     ///
     /// ```
     /// # use sparse_vector::prelude::*;
-    /// let v = &mut SparseVec::from_iter([1, 2, 0, 4, 5]);
-    /// let s = v.slice_mut(1..4);
-    /// let iter = &mut s.sparse_reader();
-    /// assert_eq!(iter.next(), Some((0, &2)));
-    /// assert_eq!(iter.next(), Some((2, &4)));
-    /// assert_eq!(iter.next(), None);
+    /// let v = &mut SparseVec::<_>::from_iter([1, 2, 3, 4, 5]);
+    /// v.slice_mut(..2).copy_from_slice(&[0, 9]);
+    /// assert_eq!(v.to_vec(), vec![0, 9, 3, 4, 5]);
     /// ```
-    pub fn sparse_reader(&self) -> SparseReader<'_, T> {
-        self.slice_ref().sparse_reader()
+    #[track_caller]
+    pub fn copy_from_slice(&mut self, src: &[T])
+    where
+        T: Copy,
+    {
+        self.clone_from_slice(src);
     }
 
-    /// Returns none padding elements writer.
+    /// Reverses the order of elements.
+    ///
+    /// Stored entries are drained once and stored again with remapped
+    /// indexes as is (even if they are treated as padding), so this takes
+    /// O(nnp log nnp) time.
     ///
     /// # Examples
     ///
     /// ```
     /// # use sparse_vector::prelude::*;
-    /// let v = &mut SparseVec::from_iter([1, 0, 3, 0, 5, 0, 7]);
-    /// let s = &mut v.slice_mut(1..5);
-    /// {
-    ///     let w = &mut s.sparse_writer();
-    ///     while let Some((_idx, val)) = w.next() {
-    ///         *val += 1;
-    ///     }
-    /// }
-    ///
-    /// assert_eq!(v.to_vec(), vec![1, 0, 4, 0, 6, 0, 7]);
+    /// let v = &mut SparseVec::<_>::from_iter([1, 2, 0, 3, 4]);
+    /// v.slice_mut(1..).reverse();
+    /// assert_eq!(v.to_vec(), vec![1, 4, 3, 0, 2]);
     /// ```
-    pub fn sparse_writer(&mut self) -> SparseWriter<'_, T> {
-        SparseWriter::new(self.vec, self.range.clone())
+    pub fn reverse(&mut self) {
+        let Range { start, end } = self.range;
+        let mut entries = self.vec.map.drain_range(start..end);
+        entries.reverse();
+        self.restore_stored(entries, |index| start + end - 1 - index);
     }
 
-    /// Takes the value of index, leaving padding value.
+    /// Rotates elements in-place such that the first `mid` elements
+    /// move to the end.
+    ///
+    /// Stored entries are drained once and stored again with remapped
+    /// indexes as is (even if they are treated as padding), so this takes
+    /// O(nnp log nnp) time.
     ///
     /// # Panics
     ///
-    /// Panics if `index` is not less than vector length.
+    /// Panics if `mid` is greater than this slice length.
     ///
     /// # Examples
     ///
     /// ```
     /// # use sparse_vector::prelude::*;
-    /// let v = &mut SparseVec::from_iter([1, 2, 3, 4, 5]);
-    /// let s = &mut v.slice_mut(1..4);
-    /// let mut r = s.take(1);
-    /// assert_eq!(r, 3);
-    /// assert_eq!(v.to_vec(), vec![1, 2, 0, 4, 5]);
+    /// let v = &mut SparseVec::<_>::from_iter([1, 2, 0, 3, 4]);
+    /// v.slice_mut(1..).rotate_left(1);
+    /// assert_eq!(v.to_vec(), vec![1, 0, 3, 4, 2]);
     /// ```
-    pub fn take(&mut self, index: usize) -> T {
-        assert!(index < self.range.len());
-        let removed = self.vec.map.remove(&(self.range.start + index));
-        removed.unwrap_or(self.vec.padding_val())
+    pub fn rotate_left(&mut self, mid: usize) {
+        assert!(mid <= self.len());
+        self.rotate_right(self.len() - mid);
     }
 
-    /// Returns value editor.
+    /// Rotates elements in-place such that the last `k` elements
+    /// move to the front.
+    ///
+    /// Stored entries are drained once and stored again with remapped
+    /// indexes as is (even if they are treated as padding), so this takes
+    /// O(nnp log nnp) time.
     ///
     /// # Panics
     ///
-    /// Panics if `index` is not less than vector length.
+    /// Panics if `k` is greater than this slice length.
     ///
     /// # Examples
     ///
     /// ```
     /// # use sparse_vector::prelude::*;
-    /// let v = &mut SparseVec::from_iter([1, 2, 3, 4, 5]);
-    /// let s = &mut v.slice_mut(1..4);
-    /// *s.edit(1) = 42;
-    /// assert_eq!(v.to_vec(), vec![1, 2, 42, 4, 5]);
+    /// let v = &mut SparseVec::<_>::from_iter([1, 2, 0, 3, 4]);
+    /// v.slice_mut(1..).rotate_right(1);
+    /// assert_eq!(v.to_vec(), vec![1, 4, 2, 0, 3]);
     /// ```
-    pub fn edit(&mut self, index: usize) -> ValueEditor<'_, T> {
-        assert!(index < self.range.len());
-        ValueEditor::new(self.vec, self.range.start + index)
+    pub fn rotate_right(&mut self, k: usize) {
+        assert!(k <= self.len());
+
+        let Range { start, end } = self.range;
+        let len = self.len();
+        let mut entries = self.vec.map.drain_range(start..end);
+        let wraps = entries.partition_point(|x| x.0 - start < len - k);
+        entries.rotate_left(wraps);
+        self.restore_stored(entries, |index| start + (index - start + k) % len);
     }
 
-    /// Fills `self` with elements by cloning `value`.
+    /// Sorts this slice.
+    ///
+    /// This sort is stable. Only stored values are sorted, and padding
+    /// values are placed as a single block, so this takes O(nnp log nnp)
+    /// time.
     ///
     /// # Examples
     ///
     /// ```
     /// # use sparse_vector::prelude::*;
-    /// let v = &mut SparseVec::from_iter([1, 2, 3, 4, 5]);
-    /// let s = &mut v.slice_mut(1..4);
-    /// s.fill(42);
-    /// assert_eq!(v.to_vec(), vec![1, 42, 42, 42, 5]);
+    /// let v = &mut SparseVec::<_>::from_iter([9, 3, 0, -1, 0, 2]);
+    /// v.slice_mut(1..).sort();
+    /// assert_eq!(v.to_vec(), vec![9, -1, 0, 0, 2, 3]);
     /// ```
-    pub fn fill(&mut self, value: T)
+    pub fn sort(&mut self)
     where
-        T: Clone,
+        T: Ord,
     {
-        self.fill_with(|| value.clone());
+        self.sort_by(T::cmp);
     }
 
-    /// Fills `self` with elements returned by calling a closure repeatedly.
+    /// Sorts this slice with a comparator function.
+    ///
+    /// See [`sort`](Self::sort) for more.
     ///
     /// # Examples
     ///
     /// ```
     /// # use sparse_vector::prelude::*;
-    /// let v = &mut SparseVec::from_iter([1, 2, 3, 4, 5]);
-    /// let s = &mut v.slice_mut(1..4);
-    /// s.fill_with(|| 42);
-    /// assert_eq!(v.to_vec(), vec![1, 42, 42, 42, 5]);
+    /// let v = &mut SparseVec::<_>::from_iter([9, 3, 0, -1, 0, 2]);
+    /// v.slice_mut(1..).sort_by(|x, y| y.cmp(x));
+    /// assert_eq!(v.to_vec(), vec![9, 3, 2, 0, 0, -1]);
     /// ```
-    pub fn fill_with<F>(&mut self, mut f: F)
+    pub fn sort_by<F>(&mut self, mut compare: F)
     where
-        F: FnMut() -> T,
+        F: FnMut(&T, &T) -> Ordering,
     {
-        for i in 0..self.len() {
-            let value = f();
-            *self.edit(i) = value;
-        }
+        // Each entry has the number of padding values before it.
+        let entries = self.take_stored().into_iter().enumerate();
+        let entries = entries.map(|(rank, (index, value))| (index - rank, value));
+        let mut entries = entries.collect::<Vec<_>>();
+        let padding_len = self.len() - entries.len();
+        entries.sort_by(|x, y| compare(&x.1, &y.1));
+
+        // Values equal to padding keep their order relative to paddings.
+        let padding = self.vec.padding.refs();
+        let lt_len = entries.partition_point(|x| compare(&x.1, padding).is_lt());
+        let le_len = entries.partition_point(|x| compare(&x.1, padding).is_le());
+        let entries = entries.into_iter().enumerate().map(|(i, (pads, value))| {
+            let index = match i {
+                i if i < lt_len => i,
+                i if i < le_len => i + pads,
+                i => i + padding_len,
+            };
+
+            (index, value)
+        });
+
+        let entries = entries.collect::<Vec<_>>();
+        self.put_stored(entries);
     }
 
-    /// Swaps two elements.
+    /// Sorts this slice with a key extraction function.
     ///
-    /// # Panics
+    /// See [`sort`](Self::sort) for more.
     ///
-    /// Panics if `a` or `b` are out of bounds.
+    /// # Examples
+    ///
+    /// ```
+    /// # use sparse_vector::prelude::*;
+    /// let v = &mut SparseVec::<_>::from_iter([-3, 0, 2, -1, 0]);
+    /// v.slice_mut(..).sort_by_key(|x: &i32| x.abs());
+    /// assert_eq!(v.to_vec(), vec![0, 0, -1, 2, -3]);
+    /// ```
+    pub fn sort_by_key<K, F>(&mut self, mut f: F)
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        self.sort_by(|x, y| f(x).cmp(&f(y)));
+    }
+
+    /// Sorts this slice, without preserving the order of equal elements.
+    ///
+    /// Only stored values are sorted by [`slice::sort_unstable_by`], and
+    /// padding values are placed as a single block, so this takes
+    /// O(nnp log nnp) time. Values equal to padding are placed before
+    /// padding values.
     ///
     /// # Examples
     ///
     /// ```
     /// # use sparse_vector::prelude::*;
-    /// let v = &mut SparseVec::from_iter(["a", "b", "c", "d", "e"]);
-    /// let s = &mut v.slice_mut(1..4);
-    /// s.swap(0, 2);
-    /// assert_eq!(v.to_vec(), vec!["a", "d", "c", "b", "e"]);
+    /// let v = &mut SparseVec::<_>::from_iter([9, 3, 0, -1, 0, 2]);
+    /// v.slice_mut(1..).sort_unstable();
+    /// assert_eq!(v.to_vec(), vec![9, -1, 0, 0, 2, 3]);
     /// ```
-    pub fn swap(&mut self, x: usize, y: usize) {
-        assert!(x < self.len());
-        assert!(y < self.len());
+    pub fn sort_unstable(&mut self)
+    where
+        T: Ord,
+    {
+        self.sort_unstable_by(T::cmp);
+    }
 
-        if x != y {
-            let xv = self.take(x);
-            let yv = self.take(y);
+    /// Sorts this slice with a comparator function, without preserving
+    /// the order of equal elements.
+    ///
+    /// See [`sort_unstable`](Self::sort_unstable) for more.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sparse_vector::prelude::*;
+    /// let v = &mut SparseVec::<_>::from_iter([9, 3, 0, -1, 0, 2]);
+    /// v.slice_mut(1..).sort_unstable_by(|x, y| y.cmp(x));
+    /// assert_eq!(v.to_vec(), vec![9, 3, 2, 0, 0, -1]);
+    /// ```
+    pub fn sort_unstable_by<F>(&mut self, mut compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let entries = self.take_stored().into_iter();
+        let mut values = entries.map(|x| x.1).collect::<Vec<_>>();
+        let padding_len = self.len() - values.len();
+        values.sort_unstable_by(&mut compare);
 
-            if &xv != self.vec.padding_ref() {
-                *self.edit(y) = xv;
-            }
+        let padding = self.vec.padding.refs();
+        let le_len = values.partition_point(|x| compare(x, padding).is_le());
+        let entries = values.into_iter().enumerate().map(|(i, value)| {
+            let index = if i < le_len { i } else { i + padding_len };
+            (index, value)
+        });
 
-            if &yv != self.vec.padding_ref() {
-                *self.edit(x) = yv;
-            }
-        }
+        let entries = entries.collect::<Vec<_>>();
+        self.put_stored(entries);
+    }
+
+    /// Sorts this slice with a key extraction function, without
+    /// preserving the order of equal elements.
+    ///
+    /// See [`sort_unstable`](Self::sort_unstable) for more.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sparse_vector::prelude::*;
+    /// let v = &mut SparseVec::<_>::from_iter([-3, 0, 2, -1, 0]);
+    /// v.slice_mut(..).sort_unstable_by_key(|x: &i32| x.abs());
+    /// assert_eq!(v.to_vec(), vec![0, 0, -1, 2, -3]);
+    /// ```
+    pub fn sort_unstable_by_key<K, F>(&mut self, mut f: F)
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        self.sort_unstable_by(|x, y| f(x).cmp(&f(y)));
     }
 
     /// Creates a new instance.
-    pub(crate) fn new(vec: &'a mut SparseVec<T>, range: Range<usize>) -> Self {
+    pub(crate) fn new(vec: &'a mut SparseVec<T, S>, range: Range<usize>) -> Self {
         assert!(range.end <= vec.len());
         Self { vec, range }
     }
+
+    /// Removes stored entries in this slice, and returns them.
+    ///
+    /// Returned entries have indexes relative to this slice.
+    pub(crate) fn take_stored(&mut self) -> Vec<(usize, T)> {
+        let start = self.range.start;
+        let entries = self.vec.map.drain_range(self.range.clone());
+        entries.into_iter().map(|(i, x)| (i - start, x)).collect()
+    }
+
+    /// Stores entries with indexes relative to this slice.
+    ///
+    /// Values treated as padding are omitted as usual.
+    pub(crate) fn put_stored<I>(&mut self, entries: I)
+    where
+        I: IntoIterator<Item = (usize, T)>,
+    {
+        for (index, value) in entries {
+            if !self.vec.padding.is_omittable(&value) {
+                self.vec.map.insert(self.range.start + index, value);
+            }
+        }
+    }
+
+    /// Stores drained entries again with indexes remapped by `f`.
+    ///
+    /// Entries should be in ascending order of remapped indexes, so that
+    /// storages insert them in order. Values are stored as is, since they
+    /// were already stored.
+    fn restore_stored<F>(&mut self, entries: Vec<(usize, T)>, f: F)
+    where
+        F: Fn(usize) -> usize,
+    {
+        for (index, value) in entries {
+            self.vec.map.insert(f(index), value);
+        }
+    }
 }
 
-impl<'a, T> Hash for SparseSliceMut<'a, T>
+/// Compact debug formatting.
+///
+/// See [`SparseSlice`]'s implementation for more.
+impl<'a, T, S> Debug for SparseSliceMut<'a, T, S>
+where
+    T: PartialEq + Debug,
+    S: SparseStorage<T>,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.as_slice().fmt_debug("SparseSliceMut", f)
+    }
+}
+
+/// Dense formatting with elision of long padding runs.
+///
+/// See [`SparseSlice`]'s implementation for more.
+impl<'a, T, S> Display for SparseSliceMut<'a, T, S>
+where
+    T: PartialEq + Display,
+    S: SparseStorage<T>,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.as_slice(), f)
+    }
+}
+
+impl<'a, T, S> Eq for SparseSliceMut<'a, T, S>
+where
+    T: Eq,
+    S: SparseStorage<T>,
+{
+    // nop.
+}
+
+impl<'a, T, S> Hash for SparseSliceMut<'a, T, S>
 where
     T: PartialEq + Hash,
+    S: SparseStorage<T>,
 {
     fn hash<H: Hasher>(&self, state: &mut H) {
-        self.slice_ref().hash(state);
+        self.as_slice().hash(state);
     }
 }
 
-impl<'a, T> Index<usize> for SparseSliceMut<'a, T>
+impl<'a, T, S> Index<usize> for SparseSliceMut<'a, T, S>
 where
     T: PartialEq,
+    S: SparseStorage<T>,
 {
     type Output = T;
 
     fn index(&self, index: usize) -> &Self::Output {
         assert!(index < self.range.len());
-        self.slice_ref().index(index)
+        &self.vec[self.range.start + index]
     }
 }
 
-impl<'a, T> IntoIterator for &'a SparseSliceMut<'a, T>
+impl<'a, T, S> IntoIterator for &'a SparseSliceMut<'a, T, S>
 where
     T: PartialEq,
+    S: SparseStorage<T>,
 {
     type Item = &'a T;
-    type IntoIter = Iter<'a, T>;
+    type IntoIter = Iter<'a, T, S>;
 
     fn into_iter(self) -> Self::IntoIter {
-        self.slice_ref().into_iter()
+        self.iter()
     }
 }
 
-impl<'a, T> Ord for SparseSliceMut<'a, T>
+impl<'a, T, S> Ord for SparseSliceMut<'a, T, S>
 where
     T: Ord,
+    S: SparseStorage<T>,
 {
     fn cmp(&self, other: &Self) -> Ordering {
-        self.slice_ref().cmp(other.slice_ref())
+        self.as_slice().cmp(&other.as_slice())
     }
 }
 
-impl<'a, T> PartialEq for SparseSliceMut<'a, T>
+impl<'a, 'b, T, U, S, S2> PartialEq<SparseSliceMut<'b, U, S2>> for SparseSliceMut<'a, T, S>
 where
-    T: PartialEq,
+    T: PartialEq + PartialEq<U>,
+    U: PartialEq,
+    S: SparseStorage<T>,
+    S2: SparseStorage<U>,
+{
+    fn eq(&self, other: &SparseSliceMut<'b, U, S2>) -> bool {
+        self.as_slice().eq(&other.as_slice())
+    }
+}
+
+impl<'a, 'b, T, U, S, S2> PartialEq<SparseSlice<'b, U, S2>> for SparseSliceMut<'a, T, S>
+where
+    T: PartialEq + PartialEq<U>,
+    U: PartialEq,
+    S: SparseStorage<T>,
+    S2: SparseStorage<U>,
 {
-    fn eq(&self, other: &Self) -> bool {
-        self.slice_ref().eq(other.slice_ref())
+    fn eq(&self, other: &SparseSlice<'b, U, S2>) -> bool {
+        self.as_slice().eq(other)
     }
 }
 
-impl<'a, T> PartialOrd for SparseSliceMut<'a, T>
+impl<'a, T, U, S, S2> PartialEq<SparseVec<U, S2>> for SparseSliceMut<'a, T, S>
+where
+    T: PartialEq + PartialEq<U>,
+    U: PartialEq,
+    S: SparseStorage<T>,
+    S2: SparseStorage<U>,
+{
+    fn eq(&self, other: &SparseVec<U, S2>) -> bool {
+        self.as_slice().eq(&other.slice(..))
+    }
+}
+
+impl<'a, T, S> PartialOrd for SparseSliceMut<'a, T, S>
 where
     T: PartialOrd,
+    S: SparseStorage<T>,
 {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        self.slice_ref().partial_cmp(other.slice_ref())
+        self.as_slice().partial_cmp(&other.as_slice())
+    }
+}
+
+impl<'a, 'b, T, S> PartialOrd<SparseSlice<'b, T, S>> for SparseSliceMut<'a, T, S>
+where
+    T: PartialOrd,
+    S: SparseStorage<T>,
+{
+    fn partial_cmp(&self, other: &SparseSlice<'b, T, S>) -> Option<Ordering> {
+        self.as_slice().partial_cmp(other)
+    }
+}
+
+impl<'a, T, S> PartialOrd<SparseVec<T, S>> for SparseSliceMut<'a, T, S>
+where
+    T: PartialOrd,
+    S: SparseStorage<T>,
+{
+    fn partial_cmp(&self, other: &SparseVec<T, S>) -> Option<Ordering> {
+        self.as_slice().partial_cmp(&other.slice(..))
     }
 }
@@ -0,0 +1,96 @@
+//! Provider of [`SparseStorage`].
+
+use std::ops::Range;
+
+/// Storage of none padding elements.
+///
+/// [`SparseVec`] keeps its none padding elements as index-value entries
+/// in this storage. Implementations must iterate entries in ascending
+/// index order, since readers and iterators depend on it.
+///
+/// [`BTreeStorage`] is the default storage. [`VecStorage`] and
+/// [`HashStorage`] are also available.
+///
+/// # Costs
+///
+/// Costs of [`SparseVec`] operations depend on the storage. Notably,
+/// every iteration over a vector or slice calls [`range`], and
+/// [`BTreeStorage`] and [`VecStorage`] find its start in O(log nnp)
+/// time. However, [`HashStorage`] collects and sorts entries in the
+/// range on every call of [`range`] and [`range_mut`], taking
+/// O(nnp + k log k) time and O(k) memory for k entries in the range.
+///
+/// [`retain_range`] may call its callback in any index order (for
+/// example, [`HashStorage`] calls it in hash order). So, callers must
+/// not depend on the calling order.
+///
+/// [`SparseVec`]: crate::SparseVec
+/// [`range`]: Self::range
+/// [`range_mut`]: Self::range_mut
+/// [`retain_range`]: Self::retain_range
+/// [`BTreeStorage`]: crate::BTreeStorage
+/// [`VecStorage`]: crate::VecStorage
+/// [`HashStorage`]: crate::HashStorage
+pub trait SparseStorage<T>: Default {
+    /// Iterator over entries in an index range.
+    type Range<'a>: DoubleEndedIterator<Item = (&'a usize, &'a T)> + Clone
+    where
+        Self: 'a,
+        T: 'a;
+
+    /// Mutable iterator over entries in an index range.
+    type RangeMut<'a>: DoubleEndedIterator<Item = (&'a usize, &'a mut T)>
+    where
+        Self: 'a,
+        T: 'a;
+
+    /// Returns `true` if there are no entries.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns entries count.
+    fn len(&self) -> usize;
+
+    /// Returns `true` if there is an entry at `index`.
+    fn contains_key(&self, index: usize) -> bool {
+        self.get(index).is_some()
+    }
+
+    /// Returns a reference to the value at `index`.
+    fn get(&self, index: usize) -> Option<&T>;
+
+    /// Returns a mutable reference to the value at `index`.
+    fn get_mut(&mut self, index: usize) -> Option<&mut T>;
+
+    /// Inserts an entry, returning the old value at `index`.
+    fn insert(&mut self, index: usize, value: T) -> Option<T>;
+
+    /// Removes an entry, returning its value.
+    fn remove(&mut self, index: usize) -> Option<T>;
+
+    /// Removes all entries.
+    fn clear(&mut self);
+
+    /// Returns an iterator over entries in `range`.
+    fn range(&self, range: Range<usize>) -> Self::Range<'_>;
+
+    /// Returns a mutable iterator over entries in `range`.
+    fn range_mut(&mut self, range: Range<usize>) -> Self::RangeMut<'_>;
+
+    /// Retains only entries in `range` for which `f` returns `true`.
+    ///
+    /// Entries out of `range` are kept. `f` may be called in any index
+    /// order.
+    fn retain_range<F>(&mut self, range: Range<usize>, f: F)
+    where
+        F: FnMut(usize, &mut T) -> bool;
+
+    /// Removes entries in `range`, returning them in index order.
+    fn drain_range(&mut self, range: Range<usize>) -> Vec<(usize, T)>;
+
+    /// Moves entries at `start` or after by `diff` indexes.
+    ///
+    /// Caller must ensure that moved entries do not collide with others.
+    fn shift_keys(&mut self, start: usize, diff: isize);
+}
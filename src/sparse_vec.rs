@@ -1,15 +1,20 @@
 //! Provider of [`SparseVec`].
 
+use crate::BTreeStorage;
+use crate::DenseHash;
 use crate::Padding;
+use crate::SparseStorage;
 use crate::ValueEditor;
-use crate::aliases::*;
 use crate::common::*;
 use crate::iters::*;
 use crate::prelude::*;
 use std::cmp::Ordering;
-use std::fmt::Debug;
+use std::collections::BTreeMap;
+use std::fmt::{self, Debug, Display, Formatter};
 use std::hash::{Hash, Hasher};
-use std::ops::{Index, RangeBounds};
+use std::iter::{Product, Sum};
+use std::ops::{Index, Mul, RangeBounds, Sub};
+use std::panic::panic_any;
 
 /// A sparse vector.
 ///
@@ -24,7 +29,7 @@ use std::ops::{Index, RangeBounds};
 ///
 /// ```
 /// # use sparse_vector::prelude::*;
-/// let mut v = SparseVec::new(5);
+/// let mut v = SparseVec::<_>::new(5);
 /// *v.edit(0) = 1;
 /// *v.edit(2) = 3;
 /// *v.edit(4) = 5;
@@ -40,10 +45,11 @@ use std::ops::{Index, RangeBounds};
 ///
 /// assert_eq!(v.to_vec(), vec![2, 0, 4, 0, 6]);
 /// ```
-#[derive(Clone, Debug)]
-pub struct SparseVec<T>
+#[derive(Clone)]
+pub struct SparseVec<T, S = BTreeStorage<T>>
 where
     T: PartialEq,
+    S: SparseStorage<T>,
 {
     /// Vector length.
     pub(crate) len: usize,
@@ -51,16 +57,20 @@ where
     /// Padding value.
     pub(crate) padding: Padding<T>,
 
-    /// None padding elements map.
-    pub(crate) map: Map<T>,
+    /// None padding elements storage.
+    pub(crate) map: S,
 }
 
-impl<T> SparseVec<T>
+impl<T, S> SparseVec<T, S>
 where
     T: PartialEq,
+    S: SparseStorage<T>,
 {
     /// Creates a new instance with default padding value.
     ///
+    /// Like other constructors, this works for any storage `S`. Without
+    /// type annotation, `SparseVec::<_>` selects [`BTreeStorage`].
+    ///
     /// # Examples
     ///
     /// ```
@@ -75,11 +85,7 @@ where
     where
         T: Default,
     {
-        Self {
-            len,
-            padding: Padding::by_default(),
-            map: Map::new(),
-        }
+        Self::from_padding(len, Padding::by_default())
     }
 
     /// Creates a new instance with padding value.
@@ -98,10 +104,105 @@ where
     where
         T: Clone,
     {
+        Self::from_padding(len, Padding::by_clone(padding))
+    }
+
+    /// Creates a new instance with padding value and padding predicate.
+    ///
+    /// Values for which `is_padding` returns `true` are treated as
+    /// padding value when they are stored to this vector. This is useful
+    /// for floating point values, where values close to padding value
+    /// should not use memory.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sparse_vector::prelude::*;
+    /// let mut v = SparseVec::<_>::with_padding_predicate(3, 0.0, |x: &f64| x.abs() < 1e-9);
+    /// *v.edit(0) = 1e-17;
+    /// *v.edit(1) = 0.5;
+    /// assert_eq!(v.nnp(), 1);
+    /// assert_eq!(v.to_vec(), vec![0.0, 0.5, 0.0]);
+    /// ```
+    #[must_use]
+    pub fn with_padding_predicate(len: usize, padding: T, is_padding: fn(&T) -> bool) -> Self
+    where
+        T: Clone,
+    {
+        Self::from_padding(len, Padding::by_clone(padding).with_predicate(is_padding))
+    }
+
+    /// Creates a new instance with padding value and its maker.
+    ///
+    /// `padding` is used as padding value, and fresh padding value is
+    /// created by calling `make_padding` each time it is needed. So, `T`
+    /// requires neither [`Default`] nor [`Clone`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `make_padding` makes a value not equal to `padding`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sparse_vector::prelude::*;
+    /// #[derive(Debug, PartialEq)]
+    /// struct Handle(Option<u32>);
+    ///
+    /// let mut v = SparseVec::<_>::with_padding_fn(10, Handle(None), || Handle(None));
+    /// *v.edit(5) = Handle(Some(42));
+    /// assert_eq!(v.padding_val(), Handle(None));
+    /// assert_eq!(v.take(5), Handle(Some(42)));
+    /// assert_eq!(v.take(5), Handle(None));
+    /// ```
+    #[must_use]
+    #[track_caller]
+    pub fn with_padding_fn(len: usize, padding: T, make_padding: fn() -> T) -> Self {
+        assert!(make_padding() == padding);
+        Self::from_padding(len, Padding::by_fn(padding, make_padding))
+    }
+}
+
+impl<T, S> SparseVec<T, S>
+where
+    T: PartialEq,
+    S: SparseStorage<T>,
+{
+    /// Creates a new instance with specified padding.
+    pub(crate) fn from_padding(len: usize, padding: Padding<T>) -> Self {
         Self {
             len,
-            padding: Padding::by_clone(padding),
-            map: Map::new(),
+            padding,
+            map: S::default(),
+        }
+    }
+
+    /// Moves elements into a new vector with another storage.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sparse_vector::prelude::*;
+    /// # use sparse_vector::VecStorage;
+    /// let v = SparseVec::<_>::from_iter([1, 0, 3]);
+    /// let w = v.into_storage::<VecStorage<_>>();
+    /// assert_eq!(w.to_vec(), vec![1, 0, 3]);
+    /// assert_eq!(w.nnp(), 2);
+    /// ```
+    #[must_use]
+    pub fn into_storage<S2>(mut self) -> SparseVec<T, S2>
+    where
+        S2: SparseStorage<T>,
+    {
+        let mut map = S2::default();
+        for (index, value) in self.map.drain_range(0..self.len) {
+            map.insert(index, value);
+        }
+
+        SparseVec {
+            len: self.len,
+            padding: self.padding,
+            map,
         }
     }
 
@@ -124,6 +225,11 @@ where
 
     /// Returns `true` if this contains no none padding elements.
     ///
+    /// This is same as `nnp() == 0`. So, in [retaining mode], this
+    /// returns `false` if values equal to padding value are stored.
+    ///
+    /// [retaining mode]: Self::set_retains_padding
+    ///
     /// # Examples
     ///
     /// ```
@@ -155,6 +261,12 @@ where
 
     /// Returns NNP (the Number of None Padding elements).
     ///
+    /// NNP is the number of stored elements. In [retaining mode], stored
+    /// values equal to padding value are also counted, even though they
+    /// are treated as padding by comparison and hashing.
+    ///
+    /// [retaining mode]: Self::set_retains_padding
+    ///
     /// # Examples
     ///
     /// ```
@@ -198,13 +310,80 @@ where
         self.padding.value()
     }
 
+    /// Returns `true` if `value` is treated as padding value.
+    ///
+    /// Usually, this is same as comparing `value` with padding value.
+    /// But if this vector is created by [`with_padding_predicate`],
+    /// the predicate is used instead.
+    ///
+    /// [`with_padding_predicate`]: Self::with_padding_predicate
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sparse_vector::prelude::*;
+    /// let v = SparseVec::<_>::with_padding_predicate(10, 0.0, |x: &f64| x.abs() < 1e-9);
+    /// assert!(v.is_padding(&0.0));
+    /// assert!(v.is_padding(&1e-17));
+    /// assert!(!v.is_padding(&1e-3));
+    /// ```
+    #[must_use]
+    pub fn is_padding(&self, value: &T) -> bool {
+        self.padding.is_padding(value)
+    }
+
+    /// Returns `true` if values treated as padding are retained when stored.
+    ///
+    /// See [`set_retains_padding`] for more.
+    ///
+    /// [`set_retains_padding`]: Self::set_retains_padding
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sparse_vector::prelude::*;
+    /// let mut v = SparseVec::<i32>::new(10);
+    /// assert!(!v.retains_padding());
+    ///
+    /// v.set_retains_padding(true);
+    /// assert!(v.retains_padding());
+    /// ```
+    #[must_use]
+    pub fn retains_padding(&self) -> bool {
+        self.padding.retains()
+    }
+
+    /// Returns `true` if the element of index is stored in this vector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not less than vector length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sparse_vector::prelude::*;
+    /// let mut v = SparseVec::<i32>::new(3);
+    /// v.set_retains_padding(true);
+    /// *v.edit(0) = 1;
+    /// *v.edit(1) = 0;
+    /// assert!(v.is_stored(0));
+    /// assert!(v.is_stored(1));
+    /// assert!(!v.is_stored(2));
+    /// ```
+    #[must_use]
+    pub fn is_stored(&self, index: usize) -> bool {
+        assert!(index < self.len);
+        self.map.contains_key(index)
+    }
+
     /// Returns a vector with the same contents of this sparse vector.
     ///
     /// # Examples
     ///
     /// ```
     /// # use sparse_vector::prelude::*;
-    /// let v = SparseVec::from_iter([1, 2, 3]);
+    /// let v = SparseVec::<_>::from_iter([1, 2, 3]);
     /// assert_eq!(v.to_vec(), vec![1, 2, 3]);
     /// ```
     #[must_use]
@@ -228,11 +407,11 @@ where
     ///
     /// ```
     /// # use sparse_vector::prelude::*;
-    /// let v = SparseVec::from_iter([1, 2, 3, 4, 5]);
+    /// let v = SparseVec::<_>::from_iter([1, 2, 3, 4, 5]);
     /// let s = v.slice(1..4);
     /// assert_eq!(s.to_vec(), vec![2, 3, 4]);
     /// ```
-    pub fn slice<R>(&self, range: R) -> SparseSlice<'_, T>
+    pub fn slice<R>(&self, range: R) -> SparseSlice<'_, T, S>
     where
         R: RangeBounds<usize>,
     {
@@ -240,20 +419,41 @@ where
         SparseSlice::new(self, range)
     }
 
+    /// Returns a slice of specified range, or `None` if out of bounds.
+    ///
+    /// This is a non-panicking version of [`slice`](Self::slice).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sparse_vector::prelude::*;
+    /// let v = SparseVec::<_>::from_iter([1, 2, 3, 4, 5]);
+    /// assert_eq!(v.get_range(1..4).unwrap().to_vec(), vec![2, 3, 4]);
+    /// assert!(v.get_range(3..6).is_none());
+    /// assert!(v.get_range(3..2).is_none());
+    /// ```
+    pub fn get_range<R>(&self, range: R) -> Option<SparseSlice<'_, T, S>>
+    where
+        R: RangeBounds<usize>,
+    {
+        let range = util::checked_range(range, self.len)?;
+        Some(SparseSlice::new(self, range))
+    }
+
     /// Returns an iterator over this vector.
     ///
     /// # Examples
     ///
     /// ```
     /// # use sparse_vector::prelude::*;
-    /// let v = SparseVec::from_iter([1, 2, 3]);
+    /// let v = SparseVec::<_>::from_iter([1, 2, 3]);
     /// let iter = &mut v.iter();
     /// assert_eq!(iter.next(), Some(&1));
     /// assert_eq!(iter.next(), Some(&2));
     /// assert_eq!(iter.next(), Some(&3));
     /// assert_eq!(iter.next(), None);
     /// ```
-    pub fn iter(&self) -> Iter<'_, T> {
+    pub fn iter(&self) -> Iter<'_, T, S> {
         Iter::new(self, 0..self.len)
     }
 
@@ -263,280 +463,1252 @@ where
     ///
     /// ```
     /// # use sparse_vector::prelude::*;
-    /// let v = SparseVec::from_iter([1, 0, 3, 0, 5]);
+    /// let v = SparseVec::<_>::from_iter([1, 0, 3, 0, 5]);
     /// let iter = &mut v.sparse_reader();
     /// assert_eq!(iter.next(), Some((0, &1)));
     /// assert_eq!(iter.next(), Some((2, &3)));
     /// assert_eq!(iter.next(), Some((4, &5)));
     /// assert_eq!(iter.next(), None);
     /// ```
-    pub fn sparse_reader(&self) -> SparseReader<'_, T> {
+    pub fn sparse_reader(&self) -> SparseReader<'_, T, S> {
         SparseReader::new(self, util::normalize_range(.., self.len))
     }
 
-    /// Sets vector length.
-    ///
-    /// If specified value is less than this vector current length,
-    /// this vector will become shorter. If specifed value is greater
-    /// than this vector current length, this vector will become longer
-    /// and new elements are filled by padding value.
+    /// Returns none padding elements merge iterator with other vector.
     ///
     /// # Examples
     ///
     /// ```
     /// # use sparse_vector::prelude::*;
-    /// let mut v = SparseVec::from_iter([1, 2, 3]);
-    /// v.set_len(5);
-    /// assert_eq!(v.to_vec(), vec![1, 2, 3, 0, 0]);
+    /// let x = SparseVec::<_>::from_iter([1, 0, 0, 4, 0]);
+    /// let y = SparseVec::<_>::from_iter([0, 0, 3, 5, 0]);
+    /// let iter = &mut x.sparse_zip(&y);
+    /// assert_eq!(iter.next(), Some((0, Some(&1), None)));
+    /// assert_eq!(iter.next(), Some((2, None, Some(&3))));
+    /// assert_eq!(iter.next(), Some((3, Some(&4), Some(&5))));
+    /// assert_eq!(iter.next(), None);
     /// ```
-    pub fn set_len(&mut self, value: usize) {
-        self.len = value;
-        while let Some(last) = self.map.last_entry() {
-            if *last.key() < value {
-                return;
-            }
-
-            let _ = last.remove();
-        }
+    pub fn sparse_zip<'a, U, S2>(
+        &'a self,
+        other: &'a SparseVec<U, S2>,
+    ) -> SparseZip<'a, T, U, S, S2>
+    where
+        U: PartialEq,
+        S2: SparseStorage<U>,
+    {
+        let len = usize::min(self.len, other.len);
+        let paddings = (self.padding_ref(), other.padding_ref());
+        let s_reader = SparseReader::new(self, 0..len);
+        let o_reader = SparseReader::new(other, 0..len);
+        SparseZip::new(paddings, s_reader, o_reader)
     }
 
-    /// Returns a mutable slice of specified range.
-    ///
-    /// # Panics
+    /// Returns none padding elements zip iterator with other vector.
     ///
-    /// Panics in the following cases.
+    /// This is a shorthand of [`sparse_zip`] and [`SparseZip::into_items`].
     ///
-    /// - Range start and end is reverse order
-    /// - Range end is greater than this vector length
+    /// [`sparse_zip`]: Self::sparse_zip
     ///
     /// # Examples
     ///
     /// ```
     /// # use sparse_vector::prelude::*;
-    /// let mut v = SparseVec::from_iter([1, 2, 3, 4, 5]);
-    /// let mut s = v.slice_mut(1..4);
-    /// *s.edit(0) += 10;
-    /// *s.edit(1) += 10;
-    /// *s.edit(2) += 10;
-    /// assert_eq!(v.to_vec(), vec![1, 12, 13, 14, 5]);
+    /// let x = SparseVec::<_>::from_iter([1, 0, 0, 4, 0]);
+    /// let y = SparseVec::<_>::from_iter([0, 0, 3, 5, 0]);
+    /// let iter = &mut x.zip_sparse(&y);
+    /// assert_eq!(iter.next(), Some(ZipItem::Left(0, &1)));
+    /// assert_eq!(iter.next(), Some(ZipItem::Right(2, &3)));
+    /// assert_eq!(iter.next(), Some(ZipItem::Both(3, &4, &5)));
+    /// assert_eq!(iter.next(), None);
     /// ```
-    pub fn slice_mut<R>(&mut self, range: R) -> SparseSliceMut<'_, T>
+    pub fn zip_sparse<'a, U, S2>(
+        &'a self,
+        other: &'a SparseVec<U, S2>,
+    ) -> ZipSparse<'a, T, U, S, S2>
     where
-        R: RangeBounds<usize>,
+        U: PartialEq,
+        S2: SparseStorage<U>,
     {
-        let range = util::normalize_range(range, self.len);
-        SparseSliceMut::new(self, range)
+        self.sparse_zip(other).into_items()
     }
 
-    /// Returns a none padding elements writer.
-    ///
-    /// # Leaking
-    ///
-    /// If the returned iterator goes out of scope without being dropped
-    /// (due to [`mem::forget`], for example), new padding value will be
-    /// remain in vector.
+    /// Returns `true` if this vector contains an element equal to `value`.
     ///
-    /// [`mem::forget`]: std::mem::forget
+    /// If `value` is equal to padding value, this finishes in O(1).
     ///
     /// # Examples
     ///
     /// ```
     /// # use sparse_vector::prelude::*;
-    /// #
-    /// let mut v = SparseVec::from_iter([1, 0, 3, 0, 5]);
-    /// {
-    ///     let mut w = v.sparse_writer();
-    ///     while let Some((_idx, val)) = w.next() {
-    ///         *val += 1;
-    ///     }
-    /// }
-    ///
-    /// assert_eq!(v.to_vec(), vec![2, 0, 4, 0, 6]);
+    /// let v = SparseVec::<_>::from_iter([1, 0, 2, 0, 3]);
+    /// assert!(v.contains(&0));
+    /// assert!(v.contains(&2));
+    /// assert!(!v.contains(&4));
     /// ```
-    pub fn sparse_writer(&mut self) -> SparseWriter<'_, T> {
-        SparseWriter::new(self, 0..self.len())
+    #[must_use]
+    pub fn contains(&self, value: &T) -> bool {
+        let padding_hit = self.nnp() < self.len && self.padding_ref() == value;
+        padding_hit || self.sparse_reader().any(|x| x.1 == value)
     }
 
-    /// Takes the value of index, leaving padding value.
+    /// Returns the index of the first element satisfying `pred`.
     ///
-    /// # Panics
-    ///
-    /// Panics if `index` is not less than vector length.
+    /// See [`SparseSlice::position`] for more.
     ///
     /// # Examples
     ///
     /// ```
     /// # use sparse_vector::prelude::*;
-    /// let mut v = SparseVec::from_iter([1, 2, 3]);
-    /// let mut r = v.take(1);
-    /// assert_eq!(r, 2);
-    /// assert_eq!(v.to_vec(), vec![1, 0, 3]);
+    /// let v = SparseVec::<_>::from_iter([1, 0, 2, 0, 3]);
+    /// assert_eq!(v.position(|x| *x == 0), Some(1));
     /// ```
-    pub fn take(&mut self, index: usize) -> T {
-        assert!(index < self.len);
-        self.slice_mut(..).take(index)
+    #[must_use]
+    pub fn position<P>(&self, pred: P) -> Option<usize>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.slice(..).position(pred)
     }
 
-    /// Returns a value editor.
-    ///
-    /// # Panics
+    /// Returns the index of the last element satisfying `pred`.
     ///
-    /// Panics if `index` is not less than vector length.
+    /// See [`SparseSlice::rposition`] for more.
     ///
     /// # Examples
     ///
     /// ```
     /// # use sparse_vector::prelude::*;
-    /// let mut v = SparseVec::from_iter([1, 2, 3]);
-    /// *v.edit(1) = 42;
-    /// assert_eq!(v.to_vec(), vec![1, 42, 3]);
+    /// let v = SparseVec::<_>::from_iter([1, 0, 2, 0, 3]);
+    /// assert_eq!(v.rposition(|x| *x == 0), Some(3));
     /// ```
-    pub fn edit(&mut self, index: usize) -> ValueEditor<'_, T> {
-        assert!(index < self.len);
-        ValueEditor::new(self, index)
+    #[must_use]
+    pub fn rposition<P>(&self, pred: P) -> Option<usize>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.slice(..).rposition(pred)
     }
 
-    /// Removes the last element from and returns it, or `None` if it is empty.
+    /// Returns the first stored element satisfying `pred`.
     ///
     /// # Examples
     ///
     /// ```
     /// # use sparse_vector::prelude::*;
-    /// let mut v = SparseVec::from_iter([1, 2, 3]);
-    /// let r = v.pop();
-    /// assert_eq!(r, Some(3));
-    /// assert_eq!(v.to_vec(), vec![1, 2]);
+    /// let v = SparseVec::<_>::from_iter([1, 0, 2, 0, 3]);
+    /// assert_eq!(v.find_stored(|x| *x > 1), Some((2, &2)));
     /// ```
-    pub fn pop(&mut self) -> Option<T> {
-        if self.is_empty() {
-            return None;
-        }
-
-        let last_index = self.len - 1;
-        let last_from_map = self.map.remove(&last_index);
-        let ret = last_from_map.unwrap_or_else(|| self.padding_val());
-        self.len -= 1;
-        Some(ret)
+    #[must_use]
+    pub fn find_stored<P>(&self, pred: P) -> Option<(usize, &T)>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.slice(..).find_stored(pred)
     }
 
-    /// Appends new last element.
+    /// Returns an iterator over the indexes of elements equal to `value`.
     ///
     /// # Examples
     ///
     /// ```
     /// # use sparse_vector::prelude::*;
-    /// let mut v = SparseVec::from_iter([1, 2]);
-    /// v.push(3);
-    /// assert_eq!(v.to_vec(), vec![1, 2, 3]);
+    /// let v = SparseVec::<_>::from_iter([1, 0, 2, 1, 0]);
+    /// assert!(v.positions_of(&1).eq([0, 3]));
     /// ```
-    pub fn push(&mut self, value: T) {
-        if &value != self.padding.refs() {
-            let new_index = self.len;
-            self.map.insert(new_index, value);
-        }
-
-        self.len += 1;
+    pub fn positions_of<'a>(&'a self, value: &'a T) -> PositionsOf<'a, T, S> {
+        let reader = SparseReader::new(self, 0..self.len);
+        PositionsOf::new(value, self.padding_ref(), reader, self.len)
     }
 
-    /// Swaps two elements.
-    ///
-    /// # Panics
-    ///
-    /// Panics if `a` or `b` are out of bounds.
+    /// Returns `true` if elements are sorted in ascending order.
     ///
     /// # Examples
     ///
     /// ```
     /// # use sparse_vector::prelude::*;
-    /// let mut v = SparseVec::from_iter(["a", "b", "c", "d", "e"]);
-    /// v.swap(2, 4);
-    /// assert_eq!(v.to_vec(), vec!["a", "b", "e", "d", "c"]);
+    /// let v = SparseVec::<_>::from_iter([-1, 0, 0, 2]);
+    /// assert!(v.is_sorted());
     /// ```
-    pub fn swap(&mut self, x: usize, y: usize) {
-        assert!(x < self.len());
-        assert!(y < self.len());
-        self.slice_mut(..).swap(x, y);
+    #[must_use]
+    pub fn is_sorted(&self) -> bool
+    where
+        T: PartialOrd,
+    {
+        self.slice(..).is_sorted()
     }
 
-    /// Clears the vector, removing all values.
+    /// Binary searches this vector for `value`.
     ///
     /// # Examples
     ///
     /// ```
     /// # use sparse_vector::prelude::*;
-    /// let mut v = SparseVec::from_iter([1, 2, 3]);
-    /// v.clear();
-    /// assert!(v.is_empty());
+    /// let v = SparseVec::<_>::from_iter([-2, 0, 0, 0, 3]);
+    /// assert_eq!(v.binary_search(&3), Ok(4));
+    /// assert_eq!(v.binary_search(&1), Err(4));
     /// ```
-    pub fn clear(&mut self) {
-        self.len = 0;
-        self.map.clear();
+    pub fn binary_search(&self, value: &T) -> Result<usize, usize>
+    where
+        T: Ord,
+    {
+        self.slice(..).binary_search(value)
     }
 
-    /// Fills `self` with elements by cloning `value`.
+    /// Binary searches this sorted vector with a comparator function.
+    ///
+    /// See [`SparseSlice::binary_search_by`] for more.
     ///
     /// # Examples
     ///
     /// ```
     /// # use sparse_vector::prelude::*;
-    /// let mut v = SparseVec::from_iter([1, 2, 3]);
-    /// v.fill(42);
-    /// assert_eq!(v.to_vec(), vec![42, 42, 42]);
+    /// let v = SparseVec::<_>::from_iter([-2, 0, 0, 0, 3]);
+    /// assert_eq!(v.binary_search_by(|x| x.cmp(&-2)), Ok(0));
     /// ```
-    pub fn fill(&mut self, value: T)
+    pub fn binary_search_by<'a, F>(&'a self, f: F) -> Result<usize, usize>
     where
-        T: Clone,
+        F: FnMut(&'a T) -> Ordering,
     {
-        self.slice_mut(..).fill(value);
+        self.slice(..).binary_search_by(f)
     }
 
-    /// Fills `self` with elements returned by calling a closure repeatedly.
+    /// Returns hashing adapter compatible with dense slices.
     ///
     /// # Examples
     ///
     /// ```
     /// # use sparse_vector::prelude::*;
-    /// let mut v = SparseVec::from_iter([1, 2, 3]);
-    /// v.fill_with(|| 42);
-    /// assert_eq!(v.to_vec(), vec![42, 42, 42]);
+    /// # use std::hash::{BuildHasher, RandomState};
+    /// let v = SparseVec::<_>::from_iter([1, 0, 3, 0, 5]);
+    /// let s = RandomState::new();
+    /// assert_eq!(s.hash_one(v.dense_hash()), s.hash_one([1, 0, 3, 0, 5]));
     /// ```
-    pub fn fill_with<F>(&mut self, f: F)
-    where
-        F: FnMut() -> T,
-    {
-        self.slice_mut(..).fill_with(f);
+    pub fn dense_hash(&self) -> DenseHash<'_, T, S> {
+        DenseHash::new(self.slice(..))
     }
 
-    /// Replace values in specified range to iterator values.
-    ///
-    /// # Panics
-    ///
-    /// Panics in the following cases.
-    ///
-    /// - Range start and end is reverse order
-    /// - Range end is greater than this vector length
+    /// Returns a vector with each value mapped by `f`.
     ///
-    /// # Leaking
+    /// `f` is called only for stored values and padding value. Mapped
+    /// values equal to mapped padding value are not stored (unless in
+    /// [retaining mode], which is kept).
     ///
-    /// If the returned iterator goes out of scope without being dropped
-    /// (due to [`mem::forget`], for example), only action performed is
-    /// just setting the values within the `range` to padding values.
+    /// Padding predicate is not kept, since it is for values of `T`.
+    /// Use [`map_values_in_place`] to keep it.
     ///
-    /// [`mem::forget`]: std::mem::forget
+    /// [retaining mode]: Self::set_retains_padding
+    /// [`map_values_in_place`]: Self::map_values_in_place
     ///
     /// # Examples
     ///
     /// ```
     /// # use sparse_vector::prelude::*;
-    /// let mut v = SparseVec::from_iter([1, 2, 3, 4, 5]);
-    /// v.splice(1..3, [42, 43, 44]);
-    /// assert_eq!(v.to_vec(), vec![1, 42, 43, 44, 4, 5]);
+    /// let v = SparseVec::<_>::from_iter([1, 0, 2, 0, 3]);
+    /// let w = v.map_values(|x| x % 2 == 1);
+    /// assert_eq!(w.padding_ref(), &false);
+    /// assert_eq!(w.nnp(), 2);
+    /// assert_eq!(w.to_vec(), vec![true, false, false, false, true]);
+    /// ```
+    #[must_use]
+    pub fn map_values<U, F>(&self, mut f: F) -> SparseVec<U>
+    where
+        U: PartialEq + Clone,
+        F: FnMut(&T) -> U,
+    {
+        let mut ret = SparseVec::<U>::with_padding(self.len, f(self.padding_ref()));
+        ret.set_retains_padding(self.retains_padding());
+        for (index, value) in self.sparse_reader() {
+            let value = f(value);
+            if !ret.padding.is_omittable(&value) {
+                ret.map.insert(index, value);
+            }
+        }
+
+        ret
+    }
+
+    /// Folds elements with callbacks for stored values and padding runs.
+    ///
+    /// See [`SparseSlice::fold_sparse`] for more.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sparse_vector::prelude::*;
+    /// let v = SparseVec::<_>::from_iter([1, 0, 0, 2, 0]);
+    /// let runs = v.fold_sparse(vec![], |acc, _, _| acc, |mut acc, _, n| {
+    ///     acc.push(n);
+    ///     acc
+    /// });
+    /// assert_eq!(runs, vec![2, 1]);
+    /// ```
+    pub fn fold_sparse<'a, B, F, G>(&'a self, init: B, on_value: F, on_padding_run: G) -> B
+    where
+        F: FnMut(B, usize, &'a T) -> B,
+        G: FnMut(B, &'a T, usize) -> B,
+    {
+        self.slice(..).fold_sparse(init, on_value, on_padding_run)
+    }
+
+    /// Returns the number of occurrences of each value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sparse_vector::prelude::*;
+    /// let v = SparseVec::<_>::from_iter([1, 0, 2, 0, 1]);
+    /// let counts = v.value_counts();
+    /// assert_eq!(counts.into_iter().collect::<Vec<_>>(), [(&0, 2), (&1, 2), (&2, 1)]);
+    /// ```
+    #[must_use]
+    pub fn value_counts(&self) -> BTreeMap<&T, usize>
+    where
+        T: Ord,
+    {
+        self.slice(..).value_counts()
+    }
+
+    /// Returns the number of elements equal to `value`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sparse_vector::prelude::*;
+    /// let v = SparseVec::<_>::from_iter([1, 0, 2, 0, 1]);
+    /// assert_eq!(v.count_value(&0), 2);
+    /// assert_eq!(v.count_value(&1), 2);
+    /// ```
+    #[must_use]
+    pub fn count_value(&self, value: &T) -> usize {
+        self.slice(..).count_value(value)
+    }
+
+    /// Returns the most frequent value.
+    ///
+    /// See [`SparseSlice::mode`] for more.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sparse_vector::prelude::*;
+    /// let v = SparseVec::<_>::from_iter([1, 2, 2, 0, 0, 0]);
+    /// assert_eq!(v.mode(), Some(&0));
+    /// ```
+    #[must_use]
+    pub fn mode(&self) -> Option<&T>
+    where
+        T: Ord,
+    {
+        self.slice(..).mode()
+    }
+
+    /// Returns the ratio of stored elements to all elements.
+    ///
+    /// Returns `0.0` if this vector is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sparse_vector::prelude::*;
+    /// let v = SparseVec::<_>::from_iter([1, 0, 2, 0]);
+    /// assert_eq!(v.density(), 0.5);
+    /// ```
+    #[must_use]
+    pub fn density(&self) -> f64 {
+        if self.is_empty() {
+            return 0.0;
+        }
+
+        self.nnp() as f64 / self.len as f64
+    }
+
+    /// Returns the sum of all elements.
+    ///
+    /// See [`SparseSlice::sum`] for more.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sparse_vector::prelude::*;
+    /// let v = SparseVec::<_>::from_iter([1, 0, 2, 0, 3]);
+    /// assert_eq!(v.sum(), 6);
+    /// ```
+    #[must_use]
+    pub fn sum(&self) -> T
+    where
+        T: Clone + Sum,
+    {
+        self.slice(..).sum()
+    }
+
+    /// Returns the product of all elements.
+    ///
+    /// See [`SparseSlice::product`] for more.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sparse_vector::prelude::*;
+    /// let v = SparseVec::<_>::with_padding(5, 2);
+    /// assert_eq!(v.product(), 32);
+    /// ```
+    #[must_use]
+    pub fn product(&self) -> T
+    where
+        T: Clone + Product,
+    {
+        self.slice(..).product()
+    }
+
+    /// Returns the dot product with other vector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `other` length is not equal to this vector length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sparse_vector::prelude::*;
+    /// let x = SparseVec::<_>::from_iter([1, 0, 2, 0, 3]);
+    /// let y = SparseVec::<_>::from_iter([0, 0, 4, 5, 6]);
+    /// assert_eq!(x.dot(&y), 26);
+    /// ```
+    #[must_use]
+    #[track_caller]
+    pub fn dot<S2>(&self, other: &SparseVec<T, S2>) -> T
+    where
+        T: Clone + Mul<Output = T> + Sum,
+        S2: SparseStorage<T>,
+    {
+        self.slice(..).dot(&other.slice(..))
+    }
+
+    /// Returns the minimum element.
+    ///
+    /// See [`SparseSlice::min_value`] for more (including why this is
+    /// not named `min`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sparse_vector::prelude::*;
+    /// let v = SparseVec::<_>::from_iter([1, 0, -2, 0, 3]);
+    /// assert_eq!(v.min_value(), Some(&-2));
+    /// ```
+    #[must_use]
+    pub fn min_value(&self) -> Option<&T>
+    where
+        T: PartialOrd,
+    {
+        self.slice(..).min_value()
+    }
+
+    /// Returns the maximum element.
+    ///
+    /// See [`SparseSlice::max_value`] for more (including why this is
+    /// not named `max`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sparse_vector::prelude::*;
+    /// let v = SparseVec::<_>::from_iter([1, 0, -2, 0, 3]);
+    /// assert_eq!(v.max_value(), Some(&3));
+    /// ```
+    #[must_use]
+    pub fn max_value(&self) -> Option<&T>
+    where
+        T: PartialOrd,
+    {
+        self.slice(..).max_value()
+    }
+
+    /// Returns the index of the maximum element.
+    ///
+    /// See [`SparseSlice::argmax`] for more.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sparse_vector::prelude::*;
+    /// let v = SparseVec::<_>::from_iter([-1, 0, -2, 0, -3]);
+    /// assert_eq!(v.argmax(), Some(1));
+    /// ```
+    #[must_use]
+    pub fn argmax(&self) -> Option<usize>
+    where
+        T: PartialOrd,
+    {
+        self.slice(..).argmax()
+    }
+
+    /// Sets vector length.
+    ///
+    /// If specified value is less than this vector current length,
+    /// this vector will become shorter. If specifed value is greater
+    /// than this vector current length, this vector will become longer
+    /// and new elements are filled by padding value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sparse_vector::prelude::*;
+    /// let mut v = SparseVec::<_>::from_iter([1, 2, 3]);
+    /// v.set_len(5);
+    /// assert_eq!(v.to_vec(), vec![1, 2, 3, 0, 0]);
+    /// ```
+    pub fn set_len(&mut self, value: usize) {
+        if value < self.len {
+            self.map.retain_range(value..self.len, |_, _| false);
+        }
+
+        self.len = value;
+    }
+
+    /// Sets flag to retain values treated as padding when stored.
+    ///
+    /// Usually, values treated as padding are removed from storage when
+    /// they are stored (by [`push`], [`extend`], [`edit`], and so on).
+    /// If this flag is `true`, such values are stored explicitly, so that
+    /// structural none padding elements are preserved. Already stored
+    /// values can be removed by [`compact`].
+    ///
+    /// [`push`]: Self::push
+    /// [`extend`]: Self::extend
+    /// [`edit`]: Self::edit
+    /// [`compact`]: Self::compact
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sparse_vector::prelude::*;
+    /// let mut v = SparseVec::<i32>::new(0);
+    /// v.set_retains_padding(true);
+    /// v.extend([1, 0, 0]);
+    /// assert_eq!(v.nnp(), 3);
+    ///
+    /// v.set_retains_padding(false);
+    /// v.push(0);
+    /// assert_eq!(v.nnp(), 3);
+    /// assert_eq!(v.to_vec(), vec![1, 0, 0, 0]);
+    /// ```
+    pub fn set_retains_padding(&mut self, value: bool) {
+        self.padding.set_retains(value);
+    }
+
+    /// Returns a mutable slice of specified range.
+    ///
+    /// # Panics
+    ///
+    /// Panics in the following cases.
+    ///
+    /// - Range start and end is reverse order
+    /// - Range end is greater than this vector length
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sparse_vector::prelude::*;
+    /// let mut v = SparseVec::<_>::from_iter([1, 2, 3, 4, 5]);
+    /// let mut s = v.slice_mut(1..4);
+    /// *s.edit(0) += 10;
+    /// *s.edit(1) += 10;
+    /// *s.edit(2) += 10;
+    /// assert_eq!(v.to_vec(), vec![1, 12, 13, 14, 5]);
+    /// ```
+    pub fn slice_mut<R>(&mut self, range: R) -> SparseSliceMut<'_, T, S>
+    where
+        R: RangeBounds<usize>,
+    {
+        let range = util::normalize_range(range, self.len);
+        SparseSliceMut::new(self, range)
+    }
+
+    /// Returns a mutable slice of specified range, or `None` if out of bounds.
+    ///
+    /// This is a non-panicking version of [`slice_mut`](Self::slice_mut).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sparse_vector::prelude::*;
+    /// let mut v = SparseVec::<_>::from_iter([1, 2, 3, 4, 5]);
+    /// v.get_range_mut(1..3).unwrap().fill(0);
+    /// assert_eq!(v.to_vec(), vec![1, 0, 0, 4, 5]);
+    /// assert!(v.get_range_mut(4..=5).is_none());
+    /// ```
+    pub fn get_range_mut<R>(&mut self, range: R) -> Option<SparseSliceMut<'_, T, S>>
+    where
+        R: RangeBounds<usize>,
+    {
+        let range = util::checked_range(range, self.len)?;
+        Some(SparseSliceMut::new(self, range))
+    }
+
+    /// Returns a none padding elements writer.
+    ///
+    /// # Leaking
+    ///
+    /// If the returned iterator goes out of scope without being dropped
+    /// (due to [`mem::forget`], for example), new padding value will be
+    /// remain in vector.
+    ///
+    /// [`mem::forget`]: std::mem::forget
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sparse_vector::prelude::*;
+    /// #
+    /// let mut v = SparseVec::<_>::from_iter([1, 0, 3, 0, 5]);
+    /// {
+    ///     let mut w = v.sparse_writer();
+    ///     while let Some((_idx, val)) = w.next() {
+    ///         *val += 1;
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(v.to_vec(), vec![2, 0, 4, 0, 6]);
+    /// ```
+    pub fn sparse_writer(&mut self) -> SparseWriter<'_, T, S> {
+        SparseWriter::new(self, 0..self.len())
+    }
+
+    /// Takes the value of index, leaving padding value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not less than vector length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sparse_vector::prelude::*;
+    /// let mut v = SparseVec::<_>::from_iter([1, 2, 3]);
+    /// let mut r = v.take(1);
+    /// assert_eq!(r, 2);
+    /// assert_eq!(v.to_vec(), vec![1, 0, 3]);
+    /// ```
+    pub fn take(&mut self, index: usize) -> T {
+        assert!(index < self.len);
+        self.slice_mut(..).take(index)
+    }
+
+    /// Returns a value editor.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not less than vector length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sparse_vector::prelude::*;
+    /// let mut v = SparseVec::<_>::from_iter([1, 2, 3]);
+    /// *v.edit(1) = 42;
+    /// assert_eq!(v.to_vec(), vec![1, 42, 3]);
+    /// ```
+    pub fn edit(&mut self, index: usize) -> ValueEditor<'_, T, S> {
+        assert!(index < self.len);
+        ValueEditor::new(self, index)
+    }
+
+    /// Removes the last element from and returns it, or `None` if it is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sparse_vector::prelude::*;
+    /// let mut v = SparseVec::<_>::from_iter([1, 2, 3]);
+    /// let r = v.pop();
+    /// assert_eq!(r, Some(3));
+    /// assert_eq!(v.to_vec(), vec![1, 2]);
+    /// ```
+    pub fn pop(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let last_index = self.len - 1;
+        let last_from_map = self.map.remove(last_index);
+        let ret = last_from_map.unwrap_or_else(|| self.padding_val());
+        self.len -= 1;
+        Some(ret)
+    }
+
+    /// Appends new last element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sparse_vector::prelude::*;
+    /// let mut v = SparseVec::<_>::from_iter([1, 2]);
+    /// v.push(3);
+    /// assert_eq!(v.to_vec(), vec![1, 2, 3]);
+    /// ```
+    pub fn push(&mut self, value: T) {
+        if !self.padding.is_omittable(&value) {
+            let new_index = self.len;
+            self.map.insert(new_index, value);
+        }
+
+        self.len += 1;
+    }
+
+    /// Appends elements of a sparse slice.
+    ///
+    /// If padding value of `src` is treated as padding in this vector,
+    /// only stored entries of `src` are cloned, in O(nnp log nnp) time.
+    /// Otherwise, padding elements of `src` are appended as values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sparse_vector::prelude::*;
+    /// let mut v = SparseVec::<_>::from_iter([1, 2]);
+    /// let w = SparseVec::<_>::from_iter([0, 3, 0]);
+    /// v.extend_from_sparse(&w.slice(..));
+    /// assert_eq!(v.to_vec(), vec![1, 2, 0, 3, 0]);
+    /// assert_eq!(v.nnp(), 3);
+    /// ```
+    pub fn extend_from_sparse(&mut self, src: &SparseSlice<'_, T, S>)
+    where
+        T: Clone,
+    {
+        if !self.padding.is_padding(src.padding_ref()) {
+            self.extend(src.iter().cloned());
+            return;
+        }
+
+        for (index, value) in src.sparse_reader() {
+            if !self.padding.is_omittable(value) {
+                self.map.insert(self.len + index, value.clone());
+            }
+        }
+
+        self.len += src.len();
+    }
+
+    /// Appends `n` padding elements.
+    ///
+    /// This takes O(1) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sparse_vector::prelude::*;
+    /// let mut v = SparseVec::<_>::from_iter([1, 2]);
+    /// v.extend_padding(3);
+    /// assert_eq!(v.to_vec(), vec![1, 2, 0, 0, 0]);
+    /// assert_eq!(v.nnp(), 2);
+    /// ```
+    pub fn extend_padding(&mut self, n: usize) {
+        self.len += n;
+    }
+
+    /// Swaps two elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` or `b` are out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sparse_vector::prelude::*;
+    /// let mut v = SparseVec::<_>::from_iter(["a", "b", "c", "d", "e"]);
+    /// v.swap(2, 4);
+    /// assert_eq!(v.to_vec(), vec!["a", "b", "e", "d", "c"]);
+    /// ```
+    pub fn swap(&mut self, x: usize, y: usize) {
+        assert!(x < self.len());
+        assert!(y < self.len());
+        self.slice_mut(..).swap(x, y);
+    }
+
+    /// Clears the vector, removing all values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sparse_vector::prelude::*;
+    /// let mut v = SparseVec::<_>::from_iter([1, 2, 3]);
+    /// v.clear();
+    /// assert!(v.is_empty());
+    /// ```
+    pub fn clear(&mut self) {
+        self.len = 0;
+        self.map.clear();
+    }
+
+    /// Fills `self` with elements by cloning `value`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sparse_vector::prelude::*;
+    /// let mut v = SparseVec::<_>::from_iter([1, 2, 3]);
+    /// v.fill(42);
+    /// assert_eq!(v.to_vec(), vec![42, 42, 42]);
+    /// ```
+    pub fn fill(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        self.slice_mut(..).fill(value);
+    }
+
+    /// Fills `self` with elements returned by calling a closure repeatedly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sparse_vector::prelude::*;
+    /// let mut v = SparseVec::<_>::from_iter([1, 2, 3]);
+    /// v.fill_with(|| 42);
+    /// assert_eq!(v.to_vec(), vec![42, 42, 42]);
+    /// ```
+    pub fn fill_with<F>(&mut self, f: F)
+    where
+        F: FnMut() -> T,
+    {
+        self.slice_mut(..).fill_with(f);
+    }
+
+    /// Copies elements from `src` range to another part of itself.
+    ///
+    /// Only stored entries are moved, and stored entries in destination
+    /// range which are not overwritten are removed. So, this takes
+    /// O(nnp log nnp) time.
+    ///
+    /// # Panics
+    ///
+    /// Panics in the following cases.
+    ///
+    /// - Range start and end is reverse order
+    /// - Range end is greater than this vector length
+    /// - `dest` is greater than this vector length minus `src` length
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sparse_vector::prelude::*;
+    /// let mut v = SparseVec::<_>::from_iter([1, 0, 3, 4, 5, 6]);
+    /// v.copy_within(..3, 3);
+    /// assert_eq!(v.to_vec(), vec![1, 0, 3, 1, 0, 3]);
+    /// ```
+    #[track_caller]
+    pub fn copy_within<R>(&mut self, src: R, dest: usize)
+    where
+        T: Copy,
+        R: RangeBounds<usize>,
+    {
+        let src = util::normalize_range(src, self.len);
+        assert!(dest <= self.len - src.len());
+
+        let entries = self.map.range(src.clone());
+        let entries = entries.map(|(i, x)| (i - src.start, *x));
+        let entries = entries.collect::<Vec<_>>();
+        let dest_slice = &mut self.slice_mut(dest..dest + src.len());
+        let _ = dest_slice.take_stored();
+        dest_slice.put_stored(entries);
+    }
+
+    /// Reverses the order of elements.
+    ///
+    /// See [`SparseSliceMut::reverse`] for more.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sparse_vector::prelude::*;
+    /// let mut v = SparseVec::<_>::from_iter([1, 2, 0, 3]);
+    /// v.reverse();
+    /// assert_eq!(v.to_vec(), vec![3, 0, 2, 1]);
+    /// ```
+    pub fn reverse(&mut self) {
+        self.slice_mut(..).reverse();
+    }
+
+    /// Rotates elements in-place such that the first `mid` elements
+    /// move to the end.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid` is greater than this vector length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sparse_vector::prelude::*;
+    /// let mut v = SparseVec::<_>::from_iter([1, 2, 0, 3]);
+    /// v.rotate_left(1);
+    /// assert_eq!(v.to_vec(), vec![2, 0, 3, 1]);
+    /// ```
+    pub fn rotate_left(&mut self, mid: usize) {
+        self.slice_mut(..).rotate_left(mid);
+    }
+
+    /// Rotates elements in-place such that the last `k` elements
+    /// move to the front.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k` is greater than this vector length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sparse_vector::prelude::*;
+    /// let mut v = SparseVec::<_>::from_iter([1, 2, 0, 3]);
+    /// v.rotate_right(1);
+    /// assert_eq!(v.to_vec(), vec![3, 1, 2, 0]);
+    /// ```
+    pub fn rotate_right(&mut self, k: usize) {
+        self.slice_mut(..).rotate_right(k);
+    }
+
+    /// Sorts this vector.
+    ///
+    /// See [`SparseSliceMut::sort`] for more.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sparse_vector::prelude::*;
+    /// let mut v = SparseVec::<_>::from_iter([3, 0, -1, 0, 2]);
+    /// v.sort();
+    /// assert_eq!(v.to_vec(), vec![-1, 0, 0, 2, 3]);
+    /// ```
+    pub fn sort(&mut self)
+    where
+        T: Ord,
+    {
+        self.slice_mut(..).sort();
+    }
+
+    /// Sorts this vector with a comparator function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sparse_vector::prelude::*;
+    /// let mut v = SparseVec::<_>::from_iter([3, 0, -1, 0, 2]);
+    /// v.sort_by(|x, y| y.cmp(x));
+    /// assert_eq!(v.to_vec(), vec![3, 2, 0, 0, -1]);
+    /// ```
+    pub fn sort_by<F>(&mut self, compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        self.slice_mut(..).sort_by(compare);
+    }
+
+    /// Sorts this vector with a key extraction function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sparse_vector::prelude::*;
+    /// let mut v = SparseVec::<_>::from_iter([-3, 0, 2, -1, 0]);
+    /// v.sort_by_key(|x: &i32| x.abs());
+    /// assert_eq!(v.to_vec(), vec![0, 0, -1, 2, -3]);
+    /// ```
+    pub fn sort_by_key<K, F>(&mut self, f: F)
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        self.slice_mut(..).sort_by_key(f);
+    }
+
+    /// Sorts this vector, without preserving the order of equal elements.
+    ///
+    /// See [`SparseSliceMut::sort_unstable`] for more.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sparse_vector::prelude::*;
+    /// let mut v = SparseVec::<_>::from_iter([3, 0, -1, 0, 2]);
+    /// v.sort_unstable();
+    /// assert_eq!(v.to_vec(), vec![-1, 0, 0, 2, 3]);
+    /// ```
+    pub fn sort_unstable(&mut self)
+    where
+        T: Ord,
+    {
+        self.slice_mut(..).sort_unstable();
+    }
+
+    /// Sorts this vector with a comparator function, without preserving
+    /// the order of equal elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sparse_vector::prelude::*;
+    /// let mut v = SparseVec::<_>::from_iter([3, 0, -1, 0, 2]);
+    /// v.sort_unstable_by(|x, y| y.cmp(x));
+    /// assert_eq!(v.to_vec(), vec![3, 2, 0, 0, -1]);
+    /// ```
+    pub fn sort_unstable_by<F>(&mut self, compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        self.slice_mut(..).sort_unstable_by(compare);
+    }
+
+    /// Sorts this vector with a key extraction function, without
+    /// preserving the order of equal elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sparse_vector::prelude::*;
+    /// let mut v = SparseVec::<_>::from_iter([-3, 0, 2, -1, 0]);
+    /// v.sort_unstable_by_key(|x: &i32| x.abs());
+    /// assert_eq!(v.to_vec(), vec![0, 0, -1, 2, -3]);
+    /// ```
+    pub fn sort_unstable_by_key<K, F>(&mut self, f: F)
+    where
+        K: Ord,
+        F: FnMut(&T) -> K,
+    {
+        self.slice_mut(..).sort_unstable_by_key(f);
+    }
+
+    /// Removes consecutive repeated elements.
+    ///
+    /// Padding runs are handled at once, so this takes O(nnp) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sparse_vector::prelude::*;
+    /// let mut v = SparseVec::<_>::from_iter([1, 1, 0, 0, 0, 2, 2, 0]);
+    /// v.dedup();
+    /// assert_eq!(v.to_vec(), vec![1, 0, 2, 0]);
+    /// ```
+    pub fn dedup(&mut self) {
+        self.dedup_by(|x, y| x == y);
+    }
+
+    /// Removes consecutive elements that resolve to the same key.
+    ///
+    /// See [`dedup_by`](Self::dedup_by) for more.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sparse_vector::prelude::*;
+    /// let mut v = SparseVec::<_>::from_iter([1, -1, 0, 0, 2, -2, 3]);
+    /// v.dedup_by_key(|x: &i32| x.abs());
+    /// assert_eq!(v.to_vec(), vec![1, 0, 2, 3]);
+    /// ```
+    pub fn dedup_by_key<K, F>(&mut self, mut key: F)
+    where
+        K: PartialEq,
+        F: FnMut(&T) -> K,
+    {
+        self.dedup_by(|x, y| key(x) == key(y));
+    }
+
+    /// Removes consecutive elements that satisfy a given equality relation.
+    ///
+    /// As with [`Vec::dedup_by`], `same_bucket` receives each element and
+    /// the last retained element, and the former is removed if it returns
+    /// `true`. Unlike it, elements are passed by shared references, since
+    /// padding value is shared by all padding elements. For padding runs,
+    /// `same_bucket` is called only on run boundaries, so this takes
+    /// O(nnp) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sparse_vector::prelude::*;
+    /// let mut v = SparseVec::<_>::from_iter([1, 0, 0, 2, 3, 0]);
+    /// v.dedup_by(|x, y| x / 2 == y / 2);
+    /// assert_eq!(v.to_vec(), vec![1, 2, 0]);
+    /// ```
+    pub fn dedup_by<F>(&mut self, mut same_bucket: F)
+    where
+        F: FnMut(&T, &T) -> bool,
+    {
+        let len = self.len;
+        let entries = self.slice_mut(..).take_stored();
+        let padding = self.padding.refs();
+        let mut kept = Vec::<(usize, T)>::new();
+        let mut last_is_padding = None;
+        let mut padding_dups = None;
+        let mut prev_end = 0;
+        let mut new_len = 0;
+
+        for entry in entries.into_iter().map(Some).chain([None]) {
+            let index = entry.as_ref().map_or(len, |x| x.0);
+            if index > prev_end {
+                let is_dup = match last_is_padding {
+                    None => false,
+                    Some(true) => {
+                        *padding_dups.get_or_insert_with(|| same_bucket(padding, padding))
+                    }
+                    Some(false) => same_bucket(padding, &kept.last().unwrap().1),
+                };
+
+                if !is_dup {
+                    let dups = *padding_dups.get_or_insert_with(|| same_bucket(padding, padding));
+                    new_len += if dups { 1 } else { index - prev_end };
+                    last_is_padding = Some(true);
+                }
+            }
+
+            if let Some((index, value)) = entry {
+                let is_dup = match last_is_padding {
+                    None => false,
+                    Some(true) => same_bucket(&value, padding),
+                    Some(false) => same_bucket(&value, &kept.last().unwrap().1),
+                };
+
+                if !is_dup {
+                    kept.push((new_len, value));
+                    new_len += 1;
+                    last_is_padding = Some(false);
+                }
+
+                prev_end = index + 1;
+            }
+        }
+
+        self.len = new_len;
+        self.slice_mut(..).put_stored(kept);
+    }
+
+    /// Removes stored values which are treated as padding.
+    ///
+    /// This is useful after storing values in [retaining mode].
+    ///
+    /// [retaining mode]: Self::set_retains_padding
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sparse_vector::prelude::*;
+    /// let mut v = SparseVec::<i32>::new(0);
+    /// v.set_retains_padding(true);
+    /// v.extend([1, 0, 3, 0]);
+    /// assert_eq!(v.nnp(), 4);
+    ///
+    /// v.compact();
+    /// assert_eq!(v.nnp(), 2);
+    /// assert_eq!(v.to_vec(), vec![1, 0, 3, 0]);
+    /// ```
+    pub fn compact(&mut self) {
+        let padding = &self.padding;
+        self.map
+            .retain_range(0..self.len, |_, x| !padding.is_padding(x));
+    }
+
+    /// Removes stored values whose distance from padding value is `eps` or less.
+    ///
+    /// Removed values become padding value. This is useful to clean up
+    /// the values which are effectively same as padding value, such as
+    /// the results of floating point calculations.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sparse_vector::prelude::*;
+    /// let mut v = SparseVec::<_>::from_iter([1.0, 1e-17, -1e-17, -1.0]);
+    /// v.prune(1e-9);
+    /// assert_eq!(v.nnp(), 2);
+    /// assert_eq!(v.to_vec(), vec![1.0, 0.0, 0.0, -1.0]);
+    /// ```
+    pub fn prune(&mut self, eps: T)
+    where
+        T: Copy + PartialOrd + Sub<Output = T>,
+    {
+        let padding = *self.padding.refs();
+        self.map.retain_range(0..self.len, |_, x| {
+            let value = *x;
+            let diff = if value < padding {
+                padding - value
+            } else {
+                value - padding
+            };
+
+            diff > eps
+        });
+    }
+
+    /// Maps each value by `f` in place.
+    ///
+    /// `f` is called only for stored values and padding value. Mapped
+    /// values treated as mapped padding value are removed (unless in
+    /// [retaining mode]). Calling order of `f` for stored values depends
+    /// on the storage (see [`SparseStorage::retain_range`]).
+    ///
+    /// Padding predicate and retaining mode are kept. Padding predicate
+    /// is applied to mapped values as is, so it is dropped if it does
+    /// not treat mapped padding value as padding (then, only values equal
+    /// to mapped padding value are treated as padding).
+    ///
+    /// [retaining mode]: Self::set_retains_padding
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sparse_vector::prelude::*;
+    /// let mut v = SparseVec::<_>::from_iter([1, 0, 2, 0, 3]);
+    /// v.map_values_in_place(|x| x / 2);
+    /// assert_eq!(v.nnp(), 2);
+    /// assert_eq!(v.to_vec(), vec![0, 0, 1, 0, 1]);
+    /// ```
+    pub fn map_values_in_place<F>(&mut self, mut f: F)
+    where
+        T: Clone,
+        F: FnMut(&T) -> T,
+    {
+        let value = f(self.padding.refs());
+        self.padding.set_value(value);
+
+        let padding = &self.padding;
+        self.map.retain_range(0..self.len, |_, x| {
+            *x = f(x);
+            !padding.is_omittable(x)
+        });
+    }
+
+    /// Replace values in specified range to iterator values.
+    ///
+    /// # Panics
+    ///
+    /// Panics in the following cases.
+    ///
+    /// - Range start and end is reverse order
+    /// - Range end is greater than this vector length
+    ///
+    /// # Leaking
+    ///
+    /// If the returned iterator goes out of scope without being dropped
+    /// (due to [`mem::forget`], for example), only action performed is
+    /// just setting the values within the `range` to padding values.
+    ///
+    /// [`mem::forget`]: std::mem::forget
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sparse_vector::prelude::*;
+    /// let mut v = SparseVec::<_>::from_iter([1, 2, 3, 4, 5]);
+    /// v.splice(1..3, [42, 43, 44]);
+    /// assert_eq!(v.to_vec(), vec![1, 42, 43, 44, 4, 5]);
     /// ```
     pub fn splice<R, I>(
         &mut self,
         range: R,
         replace_with: I,
-    ) -> Splice<'_, <I as IntoIterator>::IntoIter>
+    ) -> Splice<'_, <I as IntoIterator>::IntoIter, S>
     where
         R: RangeBounds<usize>,
         I: IntoIterator<Item = T>,
@@ -546,25 +1718,111 @@ where
     }
 }
 
-impl<T> Default for SparseVec<T>
+/// Implements norms for floating point vector.
+macro_rules! impl_norms {
+    ($t:ty) => {
+        impl<S> SparseVec<$t, S>
+        where
+            S: SparseStorage<$t>,
+        {
+            /// Returns the L1 norm, sum of absolute values.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// # use sparse_vector::prelude::*;
+            #[doc = concat!("let v = SparseVec::<", stringify!($t), ">::from_iter([3.0, 0.0, -4.0]);")]
+            /// assert_eq!(v.norm_l1(), 7.0);
+            /// ```
+            #[must_use]
+            pub fn norm_l1(&self) -> $t {
+                self.slice(..).norm_l1()
+            }
+
+            /// Returns the L2 norm, square root of sum of squares.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// # use sparse_vector::prelude::*;
+            #[doc = concat!("let v = SparseVec::<", stringify!($t), ">::from_iter([3.0, 0.0, -4.0]);")]
+            /// assert_eq!(v.norm_l2(), 5.0);
+            /// ```
+            #[must_use]
+            pub fn norm_l2(&self) -> $t {
+                self.slice(..).norm_l2()
+            }
+
+            /// Returns the L-infinity norm, maximum of absolute values.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// # use sparse_vector::prelude::*;
+            #[doc = concat!("let v = SparseVec::<", stringify!($t), ">::from_iter([3.0, 0.0, -4.0]);")]
+            /// assert_eq!(v.norm_inf(), 4.0);
+            /// ```
+            #[must_use]
+            pub fn norm_inf(&self) -> $t {
+                self.slice(..).norm_inf()
+            }
+        }
+    };
+}
+
+impl_norms!(f32);
+impl_norms!(f64);
+
+/// Compact debug formatting.
+///
+/// Only none padding elements are shown, like
+/// `SparseVec(len=1000, pad=0, {3: 1, 50: 2})`. With alternate
+/// flag (`{:#?}`), short vectors are shown in dense form.
+impl<T, S> Debug for SparseVec<T, S>
+where
+    T: PartialEq + Debug,
+    S: SparseStorage<T>,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.slice(..).fmt_debug("SparseVec", f)
+    }
+}
+
+impl<T, S> Default for SparseVec<T, S>
 where
     T: PartialEq + Default,
+    S: SparseStorage<T>,
 {
     fn default() -> Self {
-        Self::new(0)
+        Self::from_padding(0, Padding::by_default())
+    }
+}
+
+/// Dense formatting with elision of long padding runs.
+///
+/// See [`SparseSlice`]'s implementation for more.
+impl<T, S> Display for SparseVec<T, S>
+where
+    T: PartialEq + Display,
+    S: SparseStorage<T>,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.slice(..), f)
     }
 }
 
-impl<T> Eq for SparseVec<T>
+impl<T, S> Eq for SparseVec<T, S>
 where
     T: Eq,
+    S: SparseStorage<T>,
 {
     // nop.
 }
 
-impl<T> Extend<T> for SparseVec<T>
+impl<T, S> Extend<T> for SparseVec<T, S>
 where
     T: PartialEq,
+    S: SparseStorage<T>,
 {
     fn extend<I>(&mut self, iter: I)
     where
@@ -572,16 +1830,17 @@ where
     {
         for item in iter {
             self.len += 1;
-            if &item != self.padding.refs() {
+            if !self.padding.is_omittable(&item) {
                 self.map.insert(self.len - 1, item);
             }
         }
     }
 }
 
-impl<'a, T> Extend<&'a T> for SparseVec<T>
+impl<'a, T, S> Extend<&'a T> for SparseVec<T, S>
 where
     T: PartialEq + Copy,
+    S: SparseStorage<T>,
 {
     fn extend<I>(&mut self, iter: I)
     where
@@ -591,27 +1850,74 @@ where
     }
 }
 
-impl<T, const N: usize> From<[T; N]> for SparseVec<T>
+/// Scatters values to specified indexes.
+///
+/// Values are written like [`SparseVec::edit`]. If an index is out of
+/// bounds, this vector is lengthened to contain it, filling new elements
+/// with padding value.
+///
+/// # Examples
+///
+/// ```
+/// # use sparse_vector::prelude::*;
+/// let mut v = SparseVec::<_>::from_iter([1, 2, 3]);
+/// v.extend([(1, 0), (4, 5)]);
+/// assert_eq!(v.to_vec(), vec![1, 0, 3, 0, 5]);
+/// ```
+///
+/// # Panics
+///
+/// Panics if an index is [`usize::MAX`], since no length can contain it.
+/// Values before it are already written.
+impl<T, S> Extend<(usize, T)> for SparseVec<T, S>
+where
+    T: PartialEq,
+    S: SparseStorage<T>,
+{
+    #[track_caller]
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = (usize, T)>,
+    {
+        for (index, value) in iter {
+            let Some(len) = index.checked_add(1) else {
+                panic_any(msg::index_too_large(index));
+            };
+
+            self.len = self.len.max(len);
+            if self.padding.is_omittable(&value) {
+                self.map.remove(index);
+            } else {
+                self.map.insert(index, value);
+            }
+        }
+    }
+}
+
+impl<T, S, const N: usize> From<[T; N]> for SparseVec<T, S>
 where
     T: PartialEq + Default,
+    S: SparseStorage<T>,
 {
     fn from(value: [T; N]) -> Self {
         Self::from_iter(value)
     }
 }
 
-impl<T> From<Vec<T>> for SparseVec<T>
+impl<T, S> From<Vec<T>> for SparseVec<T, S>
 where
     T: PartialEq + Default,
+    S: SparseStorage<T>,
 {
     fn from(value: Vec<T>) -> Self {
         Self::from_iter(value)
     }
 }
 
-impl<T> FromIterator<T> for SparseVec<T>
+impl<T, S> FromIterator<T> for SparseVec<T, S>
 where
     T: PartialEq + Default,
+    S: SparseStorage<T>,
 {
     fn from_iter<I>(iter: I) -> Self
     where
@@ -623,83 +1929,260 @@ where
     }
 }
 
-impl<T> Hash for SparseVec<T>
+impl<T, S> Hash for SparseVec<T, S>
 where
     T: PartialEq + Hash,
+    S: SparseStorage<T>,
 {
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.slice(..).hash(state);
     }
 }
 
-impl<T> Index<usize> for SparseVec<T>
+impl<T, S> Index<usize> for SparseVec<T, S>
 where
     T: PartialEq,
+    S: SparseStorage<T>,
 {
     type Output = T;
 
     fn index(&self, index: usize) -> &Self::Output {
         assert!(index < self.len);
-        self.map.get(&index).unwrap_or(self.padding.refs())
+        self.map.get(index).unwrap_or(self.padding.refs())
     }
 }
 
-impl<T> IntoIterator for SparseVec<T>
+impl<T, S> IntoIterator for SparseVec<T, S>
 where
     T: PartialEq,
+    S: SparseStorage<T>,
 {
     type Item = T;
-    type IntoIter = IntoIter<T>;
+    type IntoIter = IntoIter<T, S>;
 
     fn into_iter(self) -> Self::IntoIter {
         IntoIter::new(self)
     }
 }
 
-impl<'a, T> IntoIterator for &'a SparseVec<T>
+impl<'a, T, S> IntoIterator for &'a SparseVec<T, S>
 where
     T: PartialEq,
+    S: SparseStorage<T>,
 {
     type Item = &'a T;
-    type IntoIter = Iter<'a, T>;
+    type IntoIter = Iter<'a, T, S>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
 
-impl<T> Ord for SparseVec<T>
+impl<T, S> Ord for SparseVec<T, S>
 where
     T: Ord,
+    S: SparseStorage<T>,
 {
     fn cmp(&self, other: &Self) -> Ordering {
         self.slice(..).cmp(&other.slice(..))
     }
 }
 
-impl<T> PartialEq for SparseVec<T>
+impl<T, U, S, S2> PartialEq<SparseVec<U, S2>> for SparseVec<T, S>
 where
-    T: PartialEq,
+    T: PartialEq + PartialEq<U>,
+    U: PartialEq,
+    S: SparseStorage<T>,
+    S2: SparseStorage<U>,
 {
-    fn eq(&self, other: &Self) -> bool {
+    fn eq(&self, other: &SparseVec<U, S2>) -> bool {
         self.slice(..).eq(&other.slice(..))
     }
 }
 
-impl<T> PartialOrd for SparseVec<T>
+impl<'a, T, U, S, S2> PartialEq<SparseSlice<'a, U, S2>> for SparseVec<T, S>
+where
+    T: PartialEq + PartialEq<U>,
+    U: PartialEq,
+    S: SparseStorage<T>,
+    S2: SparseStorage<U>,
+{
+    fn eq(&self, other: &SparseSlice<'a, U, S2>) -> bool {
+        self.slice(..).eq(other)
+    }
+}
+
+impl<'a, T, U, S, S2> PartialEq<SparseSliceMut<'a, U, S2>> for SparseVec<T, S>
+where
+    T: PartialEq + PartialEq<U>,
+    U: PartialEq,
+    S: SparseStorage<T>,
+    S2: SparseStorage<U>,
+{
+    fn eq(&self, other: &SparseSliceMut<'a, U, S2>) -> bool {
+        self.slice(..).eq(other)
+    }
+}
+
+impl<T, U, S> PartialEq<[U]> for SparseVec<T, S>
+where
+    T: PartialEq + PartialEq<U>,
+    S: SparseStorage<T>,
+{
+    fn eq(&self, other: &[U]) -> bool {
+        self.slice(..).eq(other)
+    }
+}
+
+impl<T, U, S> PartialEq<Vec<U>> for SparseVec<T, S>
+where
+    T: PartialEq + PartialEq<U>,
+    S: SparseStorage<T>,
+{
+    fn eq(&self, other: &Vec<U>) -> bool {
+        self.slice(..).eq(other)
+    }
+}
+
+impl<T, U, S, const N: usize> PartialEq<[U; N]> for SparseVec<T, S>
+where
+    T: PartialEq + PartialEq<U>,
+    S: SparseStorage<T>,
+{
+    fn eq(&self, other: &[U; N]) -> bool {
+        self.slice(..).eq(other)
+    }
+}
+
+impl<T, S> PartialOrd for SparseVec<T, S>
 where
     T: PartialOrd,
+    S: SparseStorage<T>,
 {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         self.slice(..).partial_cmp(&other.slice(..))
     }
 }
 
-impl<T> From<SparseVec<T>> for Vec<T>
+impl<'a, T, S> PartialOrd<SparseSlice<'a, T, S>> for SparseVec<T, S>
+where
+    T: PartialOrd,
+    S: SparseStorage<T>,
+{
+    fn partial_cmp(&self, other: &SparseSlice<'a, T, S>) -> Option<Ordering> {
+        self.slice(..).partial_cmp(other)
+    }
+}
+
+impl<'a, T, S> PartialOrd<SparseSliceMut<'a, T, S>> for SparseVec<T, S>
+where
+    T: PartialOrd,
+    S: SparseStorage<T>,
+{
+    fn partial_cmp(&self, other: &SparseSliceMut<'a, T, S>) -> Option<Ordering> {
+        self.slice(..).partial_cmp(other)
+    }
+}
+
+impl<T, S> PartialOrd<[T]> for SparseVec<T, S>
+where
+    T: PartialOrd,
+    S: SparseStorage<T>,
+{
+    fn partial_cmp(&self, other: &[T]) -> Option<Ordering> {
+        self.slice(..).partial_cmp(other)
+    }
+}
+
+impl<T, S> PartialOrd<Vec<T>> for SparseVec<T, S>
+where
+    T: PartialOrd,
+    S: SparseStorage<T>,
+{
+    fn partial_cmp(&self, other: &Vec<T>) -> Option<Ordering> {
+        self.slice(..).partial_cmp(other)
+    }
+}
+
+impl<T, S, const N: usize> PartialOrd<[T; N]> for SparseVec<T, S>
+where
+    T: PartialOrd,
+    S: SparseStorage<T>,
+{
+    fn partial_cmp(&self, other: &[T; N]) -> Option<Ordering> {
+        self.slice(..).partial_cmp(other)
+    }
+}
+
+impl<T, S> From<SparseVec<T, S>> for Vec<T>
 where
     T: PartialEq,
+    S: SparseStorage<T>,
 {
-    fn from(value: SparseVec<T>) -> Self {
+    fn from(value: SparseVec<T, S>) -> Self {
         Vec::from_iter(value)
     }
 }
+
+impl<T, U, S2> PartialEq<SparseVec<U, S2>> for [T]
+where
+    T: PartialEq<U>,
+    U: PartialEq,
+    S2: SparseStorage<U>,
+{
+    fn eq(&self, other: &SparseVec<U, S2>) -> bool {
+        self.len() == other.len() && self.iter().zip(other).all(|(x, y)| x == y)
+    }
+}
+
+impl<T, U, S2> PartialEq<SparseVec<U, S2>> for Vec<T>
+where
+    T: PartialEq<U>,
+    U: PartialEq,
+    S2: SparseStorage<U>,
+{
+    fn eq(&self, other: &SparseVec<U, S2>) -> bool {
+        self.as_slice().eq(other)
+    }
+}
+
+impl<T, U, S2, const N: usize> PartialEq<SparseVec<U, S2>> for [T; N]
+where
+    T: PartialEq<U>,
+    U: PartialEq,
+    S2: SparseStorage<U>,
+{
+    fn eq(&self, other: &SparseVec<U, S2>) -> bool {
+        self.as_slice().eq(other)
+    }
+}
+
+impl<T, S> PartialOrd<SparseVec<T, S>> for [T]
+where
+    T: PartialOrd,
+    S: SparseStorage<T>,
+{
+    fn partial_cmp(&self, other: &SparseVec<T, S>) -> Option<Ordering> {
+        self.iter().partial_cmp(other)
+    }
+}
+
+impl<T, S> PartialOrd<SparseVec<T, S>> for Vec<T>
+where
+    T: PartialOrd,
+    S: SparseStorage<T>,
+{
+    fn partial_cmp(&self, other: &SparseVec<T, S>) -> Option<Ordering> {
+        self.as_slice().partial_cmp(other)
+    }
+}
+
+impl<T, S, const N: usize> PartialOrd<SparseVec<T, S>> for [T; N]
+where
+    T: PartialOrd,
+    S: SparseStorage<T>,
+{
+    fn partial_cmp(&self, other: &SparseVec<T, S>) -> Option<Ordering> {
+        self.as_slice().partial_cmp(other)
+    }
+}
@@ -0,0 +1,61 @@
+//! Provider of [`sparse_vec!`](crate::sparse_vec!).
+
+/// Creates a [`SparseVec`](crate::SparseVec) containing the arguments.
+///
+/// There are three forms of this macro.
+///
+/// - Dense form: `sparse_vec![1, 0, 3]`
+///
+///   Creates a vector containing the given elements. Padding value
+///   is the default value of the element type.
+///
+/// - Repeat form: `sparse_vec![0; 1_000_000]`
+///
+///   Creates a vector of the given length, whose elements are all
+///   the given padding value. This does not allocate any element.
+///
+/// - Sparse form: `sparse_vec![len = 100, pad = 7; 3 => 1, 50 => 2]`
+///
+///   Creates a vector of the given length and padding value, then
+///   sets the values of the given indexes. `pad` can be omitted, in
+///   which case padding value is the default value of element type.
+///
+/// # Panics
+///
+/// In sparse form, panics if some index is not less than `len`.
+///
+/// # Examples
+///
+/// ```
+/// # use sparse_vector::prelude::*;
+/// let v = sparse_vec![1, 0, 3];
+/// assert_eq!(v.to_vec(), vec![1, 0, 3]);
+///
+/// let v = sparse_vec![7; 1_000_000];
+/// assert_eq!(v.len(), 1_000_000);
+/// assert_eq!(v.nnp(), 0);
+///
+/// let v = sparse_vec![len = 5, pad = 7; 1 => 1, 3 => 2];
+/// assert_eq!(v.to_vec(), vec![7, 1, 7, 2, 7]);
+/// ```
+#[macro_export]
+macro_rules! sparse_vec {
+    (len = $len:expr, pad = $pad:expr; $($idx:expr => $val:expr),* $(,)?) => {{
+        #[allow(unused_mut)]
+        let mut ret = $crate::SparseVec::<_>::with_padding($len, $pad);
+        $(*ret.edit($idx) = $val;)*
+        ret
+    }};
+    (len = $len:expr; $($idx:expr => $val:expr),* $(,)?) => {{
+        #[allow(unused_mut)]
+        let mut ret = $crate::SparseVec::<_>::new($len);
+        $(*ret.edit($idx) = $val;)*
+        ret
+    }};
+    ($pad:expr; $len:expr) => {
+        $crate::SparseVec::<_>::with_padding($len, $pad)
+    };
+    ($($val:expr),* $(,)?) => {
+        <$crate::SparseVec<_> as ::std::iter::FromIterator<_>>::from_iter([$($val),*])
+    };
+}
@@ -0,0 +1,126 @@
+//! Provider of [`SparseWrite`].
+
+use crate::SparseStorage;
+use crate::ValueEditor;
+use crate::prelude::*;
+use std::ops::DerefMut;
+
+/// Write access to sparse sequences.
+///
+/// This trait is implemented by [`SparseVec`] and [`SparseSliceMut`],
+/// so that generic algorithms can accept any of them. Other storages
+/// can implement this trait with their own editor type.
+///
+/// Methods of this trait have same names as inherent methods of above
+/// types, and behave the same as them.
+///
+/// # Examples
+///
+/// ```
+/// # use sparse_vector::prelude::*;
+/// fn bump_first<S: SparseWrite<Value = i32>>(s: &mut S) {
+///     *s.edit(0) += 1;
+/// }
+///
+/// let mut v = SparseVec::<_>::from_iter([1, 0, 2, 0, 3]);
+/// bump_first(&mut v);
+/// bump_first(&mut v.slice_mut(1..4));
+/// assert_eq!(v.to_vec(), vec![2, 1, 2, 0, 3]);
+/// ```
+pub trait SparseWrite: SparseRead {
+    /// Editor of an element.
+    type Editor<'s>: DerefMut<Target = Self::Value>
+    where
+        Self: 's;
+
+    /// Returns an editor of specified index element.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    fn edit(&mut self, index: usize) -> Self::Editor<'_>;
+
+    /// Takes specified index element, leaving padding value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    fn take(&mut self, index: usize) -> Self::Value;
+
+    /// Swaps two elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `x` or `y` are out of bounds.
+    fn swap(&mut self, x: usize, y: usize);
+
+    /// Fills `self` with elements by cloning `value`.
+    fn fill(&mut self, value: Self::Value)
+    where
+        Self::Value: Clone,
+    {
+        for i in 0..self.len() {
+            *self.edit(i) = value.clone();
+        }
+    }
+}
+
+impl<'a, T, S> SparseWrite for SparseSliceMut<'a, T, S>
+where
+    T: PartialEq,
+    S: SparseStorage<T>,
+{
+    type Editor<'s>
+        = ValueEditor<'s, T, S>
+    where
+        Self: 's;
+
+    fn edit(&mut self, index: usize) -> ValueEditor<'_, T, S> {
+        SparseSliceMut::edit(self, index)
+    }
+
+    fn take(&mut self, index: usize) -> T {
+        SparseSliceMut::take(self, index)
+    }
+
+    fn swap(&mut self, x: usize, y: usize) {
+        SparseSliceMut::swap(self, x, y);
+    }
+
+    fn fill(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        SparseSliceMut::fill(self, value);
+    }
+}
+
+impl<T, S> SparseWrite for SparseVec<T, S>
+where
+    T: PartialEq,
+    S: SparseStorage<T>,
+{
+    type Editor<'s>
+        = ValueEditor<'s, T, S>
+    where
+        Self: 's;
+
+    fn edit(&mut self, index: usize) -> ValueEditor<'_, T, S> {
+        SparseVec::edit(self, index)
+    }
+
+    fn take(&mut self, index: usize) -> T {
+        SparseVec::take(self, index)
+    }
+
+    fn swap(&mut self, x: usize, y: usize) {
+        SparseVec::swap(self, x, y);
+    }
+
+    fn fill(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        SparseVec::fill(self, value);
+    }
+}
@@ -0,0 +1,108 @@
+//! Provider of [`BTreeStorage`].
+
+use crate::SparseStorage;
+use crate::aliases::*;
+use std::ops::{Bound, Range};
+
+/// B-tree storage.
+///
+/// This is the default storage of [`SparseVec`]. Both random access
+/// and edit take O(log nnp) time.
+///
+/// [`SparseVec`]: crate::SparseVec
+#[derive(Clone, Debug)]
+pub struct BTreeStorage<T> {
+    /// Entries map.
+    map: Map<T>,
+}
+
+impl<T> Default for BTreeStorage<T> {
+    fn default() -> Self {
+        Self { map: Map::new() }
+    }
+}
+
+impl<T> SparseStorage<T> for BTreeStorage<T> {
+    type Range<'a>
+        = MapRange<'a, T>
+    where
+        T: 'a;
+    type RangeMut<'a>
+        = MapRangeMut<'a, T>
+    where
+        T: 'a;
+
+    fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    fn contains_key(&self, index: usize) -> bool {
+        self.map.contains_key(&index)
+    }
+
+    fn get(&self, index: usize) -> Option<&T> {
+        self.map.get(&index)
+    }
+
+    fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.map.get_mut(&index)
+    }
+
+    fn insert(&mut self, index: usize, value: T) -> Option<T> {
+        self.map.insert(index, value)
+    }
+
+    fn remove(&mut self, index: usize) -> Option<T> {
+        self.map.remove(&index)
+    }
+
+    fn clear(&mut self) {
+        self.map.clear();
+    }
+
+    fn range(&self, range: Range<usize>) -> MapRange<'_, T> {
+        self.map.range(range)
+    }
+
+    fn range_mut(&mut self, range: Range<usize>) -> MapRangeMut<'_, T> {
+        self.map.range_mut(range)
+    }
+
+    fn retain_range<F>(&mut self, range: Range<usize>, mut f: F)
+    where
+        F: FnMut(usize, &mut T) -> bool,
+    {
+        let cursor = &mut self.map.lower_bound_mut(Bound::Included(&range.start));
+        while let Some(elm) = cursor.next() {
+            if *elm.0 >= range.end {
+                break;
+            }
+
+            if !f(*elm.0, elm.1) {
+                cursor.remove_prev();
+            }
+        }
+    }
+
+    fn drain_range(&mut self, range: Range<usize>) -> Vec<(usize, T)> {
+        let cursor = &mut self.map.lower_bound_mut(Bound::Included(&range.start));
+        let mut ret = Vec::new();
+        while cursor.peek_next().is_some_and(|x| *x.0 < range.end) {
+            ret.push(cursor.remove_next().unwrap());
+        }
+
+        ret
+    }
+
+    fn shift_keys(&mut self, start: usize, diff: isize) {
+        let cursor = self.map.lower_bound_mut(Bound::Included(&start));
+
+        #[allow(unused_unsafe)]
+        unsafe {
+            let mut cursor = cursor.with_mutable_key();
+            while let Some(elm) = cursor.next() {
+                *elm.0 = elm.0.checked_add_signed(diff).unwrap();
+            }
+        }
+    }
+}
@@ -0,0 +1,130 @@
+//! Provider of [`HashStorage`].
+
+use crate::SparseStorage;
+use std::collections::HashMap;
+use std::ops::Range;
+use std::vec::IntoIter;
+
+/// Hash map storage.
+///
+/// This storage is for point access. Random access and edit take O(1)
+/// time. However, entries are not ordered, so [`range`] and
+/// [`range_mut`] collect and sort entries on every call. It takes
+/// O(nnp + k log k) time and O(k) memory, where k is the number of
+/// entries in the range. Every iteration over a vector or slice (such
+/// as [`iter`] and [`sparse_reader`]) pays this cost, even for a short
+/// slice. Also, inserting or removing elements shifts indexes in O(nnp)
+/// time. So, this is good only for vectors which are mostly accessed at
+/// random indexes and rarely iterated.
+///
+/// [`range`]: SparseStorage::range
+/// [`range_mut`]: SparseStorage::range_mut
+/// [`iter`]: crate::SparseVec::iter
+/// [`sparse_reader`]: crate::SparseVec::sparse_reader
+///
+/// # Examples
+///
+/// ```
+/// # use sparse_vector::prelude::*;
+/// # use sparse_vector::HashStorage;
+/// let v = SparseVec::<_>::from_iter([1, 0, 3]);
+/// let mut v = v.into_storage::<HashStorage<_>>();
+/// *v.edit(1) = 2;
+/// assert_eq!(v.to_vec(), vec![1, 2, 3]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct HashStorage<T> {
+    /// Entries map.
+    map: HashMap<usize, T>,
+}
+
+impl<T> Default for HashStorage<T> {
+    fn default() -> Self {
+        Self {
+            map: HashMap::new(),
+        }
+    }
+}
+
+impl<T> SparseStorage<T> for HashStorage<T> {
+    type Range<'a>
+        = IntoIter<(&'a usize, &'a T)>
+    where
+        T: 'a;
+    type RangeMut<'a>
+        = IntoIter<(&'a usize, &'a mut T)>
+    where
+        T: 'a;
+
+    fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    fn contains_key(&self, index: usize) -> bool {
+        self.map.contains_key(&index)
+    }
+
+    fn get(&self, index: usize) -> Option<&T> {
+        self.map.get(&index)
+    }
+
+    fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.map.get_mut(&index)
+    }
+
+    fn insert(&mut self, index: usize, value: T) -> Option<T> {
+        self.map.insert(index, value)
+    }
+
+    fn remove(&mut self, index: usize) -> Option<T> {
+        self.map.remove(&index)
+    }
+
+    fn clear(&mut self) {
+        self.map.clear();
+    }
+
+    fn range(&self, range: Range<usize>) -> Self::Range<'_> {
+        let entries = self.map.iter().filter(|x| range.contains(x.0));
+        let mut entries = entries.collect::<Vec<_>>();
+        entries.sort_unstable_by_key(|x| *x.0);
+        entries.into_iter()
+    }
+
+    fn range_mut(&mut self, range: Range<usize>) -> Self::RangeMut<'_> {
+        let entries = self.map.iter_mut().filter(|x| range.contains(x.0));
+        let mut entries = entries.collect::<Vec<_>>();
+        entries.sort_unstable_by_key(|x| *x.0);
+        entries.into_iter()
+    }
+
+    fn retain_range<F>(&mut self, range: Range<usize>, mut f: F)
+    where
+        F: FnMut(usize, &mut T) -> bool,
+    {
+        self.map.retain(|i, x| !range.contains(i) || f(*i, x));
+    }
+
+    fn drain_range(&mut self, range: Range<usize>) -> Vec<(usize, T)> {
+        let indexes = self.map.keys().filter(|x| range.contains(x));
+        let mut indexes = indexes.copied().collect::<Vec<_>>();
+        indexes.sort_unstable();
+        indexes
+            .into_iter()
+            .map(|i| (i, self.map.remove(&i).unwrap()))
+            .collect()
+    }
+
+    fn shift_keys(&mut self, start: usize, diff: isize) {
+        let indexes = self.map.keys().filter(|x| **x >= start);
+        let indexes = indexes.copied().collect::<Vec<_>>();
+        let moved = indexes
+            .into_iter()
+            .map(|i| (i, self.map.remove(&i).unwrap()));
+        let moved = moved.collect::<Vec<_>>();
+        for (index, value) in moved {
+            self.map
+                .insert(index.checked_add_signed(diff).unwrap(), value);
+        }
+    }
+}
@@ -0,0 +1,9 @@
+//! Storages.
+
+pub use btree_storage::*;
+pub use hash_storage::*;
+pub use vec_storage::*;
+
+mod btree_storage;
+mod hash_storage;
+mod vec_storage;
@@ -0,0 +1,135 @@
+//! Provider of [`VecStorage`].
+
+use crate::SparseStorage;
+use std::iter::Map;
+use std::mem;
+use std::ops::Range;
+use std::slice::{Iter, IterMut};
+
+/// Sorted vector storage.
+///
+/// Entries are kept in a vector sorted by index. Random access takes
+/// O(log nnp) time and iteration is cache friendly, but edit which adds
+/// or removes an entry takes O(nnp) time. So, this is good for read
+/// mostly vectors.
+///
+/// # Examples
+///
+/// ```
+/// # use sparse_vector::prelude::*;
+/// # use sparse_vector::VecStorage;
+/// let v = SparseVec::<_>::from_iter([1, 0, 3]);
+/// let v = v.into_storage::<VecStorage<_>>();
+/// assert_eq!(v.to_vec(), vec![1, 0, 3]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct VecStorage<T> {
+    /// Entries sorted by index.
+    entries: Vec<(usize, T)>,
+}
+
+impl<T> VecStorage<T> {
+    /// Returns position of `index` entry, or position to insert it.
+    fn search(&self, index: usize) -> Result<usize, usize> {
+        self.entries.binary_search_by_key(&index, |x| x.0)
+    }
+
+    /// Returns positions of entries in `range`.
+    fn positions(&self, range: Range<usize>) -> Range<usize> {
+        let start = self.entries.partition_point(|x| x.0 < range.start);
+        let end = self.entries.partition_point(|x| x.0 < range.end);
+        start..end.max(start)
+    }
+}
+
+impl<T> Default for VecStorage<T> {
+    fn default() -> Self {
+        Self {
+            entries: Vec::new(),
+        }
+    }
+}
+
+impl<T> SparseStorage<T> for VecStorage<T> {
+    type Range<'a>
+        = Map<Iter<'a, (usize, T)>, fn(&'a (usize, T)) -> (&'a usize, &'a T)>
+    where
+        T: 'a;
+    type RangeMut<'a>
+        = Map<IterMut<'a, (usize, T)>, fn(&'a mut (usize, T)) -> (&'a usize, &'a mut T)>
+    where
+        T: 'a;
+
+    fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    fn get(&self, index: usize) -> Option<&T> {
+        let pos = self.search(index).ok()?;
+        Some(&self.entries[pos].1)
+    }
+
+    fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        let pos = self.search(index).ok()?;
+        Some(&mut self.entries[pos].1)
+    }
+
+    fn insert(&mut self, index: usize, value: T) -> Option<T> {
+        match self.search(index) {
+            Ok(pos) => Some(mem::replace(&mut self.entries[pos].1, value)),
+            Err(pos) => {
+                self.entries.insert(pos, (index, value));
+                None
+            }
+        }
+    }
+
+    fn remove(&mut self, index: usize) -> Option<T> {
+        let pos = self.search(index).ok()?;
+        Some(self.entries.remove(pos).1)
+    }
+
+    fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    fn range(&self, range: Range<usize>) -> Self::Range<'_> {
+        let positions = self.positions(range);
+        self.entries[positions].iter().map(entry_ref as _)
+    }
+
+    fn range_mut(&mut self, range: Range<usize>) -> Self::RangeMut<'_> {
+        let positions = self.positions(range);
+        self.entries[positions].iter_mut().map(entry_mut as _)
+    }
+
+    fn retain_range<F>(&mut self, range: Range<usize>, mut f: F)
+    where
+        F: FnMut(usize, &mut T) -> bool,
+    {
+        self.entries
+            .retain_mut(|x| !range.contains(&x.0) || f(x.0, &mut x.1));
+    }
+
+    fn drain_range(&mut self, range: Range<usize>) -> Vec<(usize, T)> {
+        let positions = self.positions(range);
+        self.entries.drain(positions).collect()
+    }
+
+    fn shift_keys(&mut self, start: usize, diff: isize) {
+        let pos = self.entries.partition_point(|x| x.0 < start);
+        for entry in &mut self.entries[pos..] {
+            entry.0 = entry.0.checked_add_signed(diff).unwrap();
+        }
+    }
+}
+
+/// Returns entry as references pair.
+fn entry_ref<T>(entry: &(usize, T)) -> (&usize, &T) {
+    (&entry.0, &entry.1)
+}
+
+/// Returns entry as references pair with mutable value.
+fn entry_mut<T>(entry: &mut (usize, T)) -> (&usize, &mut T) {
+    (&entry.0, &mut entry.1)
+}
@@ -1,5 +1,7 @@
 //! Provider of [`ValueEditor`].
 
+use crate::BTreeStorage;
+use crate::SparseStorage;
 use crate::prelude::*;
 use std::fmt::Debug;
 use std::mem;
@@ -8,12 +10,13 @@ use std::ops::{Deref, DerefMut};
 /// Editor on sparse vector value.
 #[must_use]
 #[derive(Debug)]
-pub struct ValueEditor<'a, T>
+pub struct ValueEditor<'a, T, S = BTreeStorage<T>>
 where
     T: PartialEq,
+    S: SparseStorage<T>,
 {
     /// Underlyiing vec.
-    vec: &'a mut SparseVec<T>,
+    vec: &'a mut SparseVec<T, S>,
 
     /// Target index.
     index: usize,
@@ -22,12 +25,13 @@ where
     new_value: Option<T>,
 }
 
-impl<'a, T> ValueEditor<'a, T>
+impl<'a, T, S> ValueEditor<'a, T, S>
 where
     T: PartialEq,
+    S: SparseStorage<T>,
 {
     /// Creates a new instance.
-    pub(crate) fn new(vec: &'a mut SparseVec<T>, index: usize) -> Self {
+    pub(crate) fn new(vec: &'a mut SparseVec<T, S>, index: usize) -> Self {
         Self {
             vec,
             index,
@@ -36,9 +40,10 @@ where
     }
 }
 
-impl<'a, T> Deref for ValueEditor<'a, T>
+impl<'a, T, S> Deref for ValueEditor<'a, T, S>
 where
     T: PartialEq,
+    S: SparseStorage<T>,
 {
     type Target = T;
 
@@ -51,14 +56,15 @@ where
     }
 }
 
-impl<'a, T> DerefMut for ValueEditor<'a, T>
+impl<'a, T, S> DerefMut for ValueEditor<'a, T, S>
 where
     T: PartialEq,
+    S: SparseStorage<T>,
 {
     fn deref_mut(&mut self) -> &mut Self::Target {
         if self.new_value.is_none() {
             let padding = self.vec.padding_val();
-            let map_value = self.vec.map.get_mut(&self.index);
+            let map_value = self.vec.map.get_mut(self.index);
             self.new_value = Some(match map_value {
                 None => padding,
                 Some(x) => mem::replace(x, padding),
@@ -69,9 +75,10 @@ where
     }
 }
 
-impl<'a, T> Drop for ValueEditor<'a, T>
+impl<'a, T, S> Drop for ValueEditor<'a, T, S>
 where
     T: PartialEq,
+    S: SparseStorage<T>,
 {
     fn drop(&mut self) {
         if self.new_value.is_none() {
@@ -79,8 +86,8 @@ where
         }
 
         let new_value = self.new_value.take().unwrap();
-        if &new_value == self.vec.padding_ref() {
-            self.vec.map.remove(&self.index);
+        if self.vec.padding.is_omittable(&new_value) {
+            self.vec.map.remove(self.index);
         } else {
             self.vec.map.insert(self.index, new_value);
         }
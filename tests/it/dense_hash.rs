@@ -0,0 +1,77 @@
+use crate::for_test::helper;
+use crate::for_test::samples::*;
+use sparse_vector::prelude::*;
+
+#[test]
+fn hash() {
+    with_bulk_hash_slice();
+    with_vec();
+    with_slice();
+    with_slice_mut();
+
+    fn with_bulk_hash_slice() {
+        for pair in SparseVecSample::pairs() {
+            for target in pair {
+                let result = helper::hash_writes(&target.dense_hash());
+                assert_eq!(result, helper::hash_writes(&target.to_vec()));
+            }
+        }
+
+        let target = SparseVec::<u8>::from_iter([1, 0, 0, 4, 0]);
+        let result = helper::hash_writes(&target.dense_hash());
+        assert_eq!(result, helper::hash_writes(&target.to_vec()));
+    }
+
+    fn with_vec() {
+        for pair in SparseVecSample::pairs() {
+            for target in pair {
+                let result = helper::hash(&target.dense_hash());
+                assert_eq!(result, helper::hash(&target.to_vec()));
+            }
+        }
+    }
+
+    fn with_slice() {
+        for pair in SparseSliceSample::pairs() {
+            for context in pair {
+                let target = context.fetch();
+                let result = helper::hash(&target.dense_hash());
+                assert_eq!(result, helper::hash(&target.to_vec()));
+            }
+        }
+    }
+
+    fn with_slice_mut() {
+        for pair in SparseSliceSample::pairs() {
+            for mut context in pair {
+                let target = context.fetch_mut();
+                let result = helper::hash(&target.dense_hash());
+                assert_eq!(result, helper::hash(&target.to_vec()));
+            }
+        }
+    }
+}
+
+#[test]
+fn eq() {
+    with_dense_hash();
+    with_dense();
+
+    fn with_dense_hash() {
+        for pair in SparseVecSample::pairs() {
+            let [x, y] = &pair;
+            let result = x.dense_hash() == y.dense_hash();
+            assert_eq!(result, x.to_vec() == y.to_vec());
+        }
+    }
+
+    fn with_dense() {
+        for pair in SparseVecSample::pairs() {
+            for target in pair {
+                let vec = target.to_vec();
+                assert!(target.dense_hash() == vec);
+                assert!(target.dense_hash() == *vec.as_slice());
+            }
+        }
+    }
+}
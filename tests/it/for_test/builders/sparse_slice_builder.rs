@@ -46,13 +46,13 @@ impl SparseSliceBuilder {
 // Building methods.
 impl SparseSliceBuilder {
     pub fn build(&self) -> SliceContext<i32> {
-        let mut vec = SparseVec::with_padding(0, self.vb.padding());
+        let mut vec = SparseVec::<_>::with_padding(0, self.vb.padding());
         vec.extend(self.vec_values());
         SliceContext::new(vec, self.range())
     }
 
     pub fn build_floats(&self) -> SliceContext<f32> {
-        let mut vec = SparseVec::with_padding(0, self.vb.padding() as f32);
+        let mut vec = SparseVec::<_>::with_padding(0, self.vb.padding() as f32);
         vec.extend(self.vec_values().iter().map(|x| *x as f32));
         SliceContext::new(vec, self.range())
     }
@@ -61,13 +61,13 @@ impl SparseVecBuilder {
 // Building methods.
 impl SparseVecBuilder {
     pub fn build(&self) -> SparseVec<i32> {
-        let mut ret = SparseVec::with_padding(0, self.vb.padding());
+        let mut ret = SparseVec::<_>::with_padding(0, self.vb.padding());
         ret.extend(self.values());
         ret
     }
 
     pub fn build_floats(&self) -> SparseVec<f32> {
-        let mut ret = SparseVec::with_padding(0, self.vb.padding() as f32);
+        let mut ret = SparseVec::<_>::with_padding(0, self.vb.padding() as f32);
         ret.extend(self.values().iter().map(|x| *x as f32));
         ret
     }
@@ -38,6 +38,29 @@ pub fn hash<T: Hash>(target: &T) -> u64 {
     hasher.finish()
 }
 
+/// Returns target hash writes, split by each method call of hasher.
+pub fn hash_writes<T: Hash>(target: &T) -> Vec<Vec<u8>> {
+    let hasher = &mut WriteRecorder::default();
+    target.hash(hasher);
+    hasher.writes.clone()
+}
+
+/// Hasher that records each write separately.
+#[derive(Default)]
+struct WriteRecorder {
+    writes: Vec<Vec<u8>>,
+}
+
+impl Hasher for WriteRecorder {
+    fn finish(&self) -> u64 {
+        self.writes.len() as u64
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        self.writes.push(bytes.to_vec());
+    }
+}
+
 /// Returns a random value within the range excluding `na`.
 pub fn rand_without<R: Rng>(rng: &mut R, range: RangeInclusive<i32>, na: i32) -> i32 {
     let adjusted_range = *range.start()..=(*range.end() - 1);
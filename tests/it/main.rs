@@ -1,11 +1,19 @@
+mod dense_hash;
 mod for_test;
 mod into_iter;
 mod iter;
+mod merge_sparse;
+mod ops;
+mod sparse_read;
 mod sparse_reader;
 mod sparse_slice;
 mod sparse_slice_mut;
+mod sparse_storage;
 mod sparse_vec;
+mod sparse_vec_macro;
+mod sparse_write;
 mod sparse_writer;
 mod sparse_writer_map;
+mod sparse_zip;
 mod splice;
 mod value_editor;
@@ -0,0 +1,76 @@
+use crate::for_test::samples::*;
+use sparse_vector::prelude::*;
+
+#[test]
+fn paddings() {
+    let x = SparseVec::<_>::with_padding(3, 1);
+    let y = SparseVec::<_>::with_padding(5, 2);
+    let (sx, sy) = (x.slice(..), y.slice(..));
+    let target = SparseSlice::merge_sparse([&sx, &sy]);
+    let result = target.paddings();
+    assert_eq!(result, [&1, &2]);
+}
+
+#[test]
+fn next() {
+    with_none();
+    with_normal();
+
+    fn with_none() {
+        let target = &mut SparseSlice::<i32>::merge_sparse([]);
+        let result = target.next();
+        assert_eq!(result, None);
+    }
+
+    fn with_normal() {
+        for [x, y] in SparseVecSample::pairs() {
+            // Arrange.
+            let z = SparseVecSample::normal();
+            let (sx, sy, sz) = (x.slice(..), y.slice(..), z.slice(..));
+            let target = SparseSlice::merge_sparse([&sx, &sy, &sz]);
+
+            // Act.
+            let result = target.collect::<Vec<_>>();
+
+            // Assert.
+            assert_eq!(result, expected([&x, &y, &z]));
+        }
+    }
+}
+
+#[test]
+fn next_back() {
+    for [x, y] in SparseVecSample::pairs() {
+        // Arrange.
+        let z = SparseVecSample::normal();
+        let (sx, sy, sz) = (x.slice(..), y.slice(..), z.slice(..));
+        let target = SparseSlice::merge_sparse([&sx, &sy, &sz]);
+
+        // Act.
+        let result = target.rev().collect::<Vec<_>>();
+
+        // Assert.
+        assert!(
+            result
+                .into_iter()
+                .eq(expected([&x, &y, &z]).into_iter().rev())
+        );
+    }
+}
+
+#[test]
+fn size_hint() {
+    for [x, y] in SparseVecSample::pairs() {
+        let (sx, sy) = (x.slice(..), y.slice(..));
+        let target = SparseSlice::merge_sparse([&sx, &sy]);
+        let result = target.size_hint();
+        let len = expected([&x, &y]).len();
+        assert!(result.0 <= len && len <= result.1.unwrap());
+    }
+}
+
+fn expected<const N: usize>(vecs: [&SparseVec<i32>; N]) -> Vec<(usize, [Option<&i32>; N])> {
+    let len = vecs.iter().map(|x| x.len()).min().unwrap_or(0);
+    let elms = (0..len).map(|i| (i, vecs.map(|x| x.is_stored(i).then_some(&x[i]))));
+    elms.filter(|x| x.1.iter().any(Option::is_some)).collect()
+}
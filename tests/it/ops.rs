@@ -0,0 +1,276 @@
+use crate::for_test::samples::*;
+use sparse_vector::prelude::*;
+use test_panic::prelude::*;
+
+#[test]
+fn add() {
+    with_len_mismatch();
+    with_normal();
+    with_owned();
+    with_padding_settings();
+
+    fn with_len_mismatch() {
+        let x = SparseVec::<i32>::new(3);
+        let y = SparseVec::<i32>::new(4);
+        let result = test_panic(|| &x + &y);
+        assert!(result.is_panic());
+    }
+
+    fn with_normal() {
+        for [x, y] in same_len_pairs() {
+            let result = &x + &y;
+            let expected = dense_op(&x, &y, |x, y| x + y);
+            assert_eq!(result, expected);
+            assert!(is_compact(&result));
+        }
+    }
+
+    fn with_owned() {
+        for [x, y] in same_len_pairs() {
+            let expected = dense_op(&x, &y, |x, y| x + y);
+            let result = x + &y;
+            assert_eq!(result, expected);
+            assert!(is_compact(&result));
+        }
+    }
+
+    fn with_padding_settings() {
+        // Arrange.
+        let [x, y] = padding_settings_pair();
+
+        // Act.
+        let result_xy = &x + &y;
+        let result_yx = &y + &x;
+
+        // Assert.
+        assert!(result_xy.retains_padding());
+        assert!(result_xy.is_padding(&1e-12));
+        assert!(!result_yx.retains_padding());
+        assert!(!result_yx.is_padding(&1e-12));
+    }
+}
+
+#[test]
+fn add_assign() {
+    with_len_mismatch();
+    with_normal();
+    with_padding_settings();
+
+    fn with_len_mismatch() {
+        let mut x = SparseVec::<i32>::new(3);
+        let y = SparseVec::<i32>::new(4);
+        let result = test_panic(|| x += &y);
+        assert!(result.is_panic());
+    }
+
+    fn with_normal() {
+        for [mut x, y] in same_len_pairs() {
+            let expected = dense_op(&x, &y, |x, y| x + y);
+            x += &y;
+            assert_eq!(x, expected);
+            assert!(is_compact(&x));
+        }
+    }
+
+    fn with_padding_settings() {
+        // Arrange.
+        let [mut x, mut y] = padding_settings_pair();
+        let (x_orig, y_orig) = (x.clone(), y.clone());
+
+        // Act.
+        x += &y_orig;
+        y += &x_orig;
+
+        // Assert.
+        assert!(x.retains_padding());
+        assert!(x.is_padding(&1e-12));
+        assert!(!y.retains_padding());
+        assert!(!y.is_padding(&1e-12));
+    }
+}
+
+#[test]
+fn div() {
+    with_ref();
+    with_owned();
+    with_padding_settings();
+    with_padding_maker();
+
+    fn with_ref() {
+        let target = SparseVec::<_>::from_iter([4, 0, 1, 0, 8]);
+        let result = &target / 2;
+        assert_eq!(result, [2, 0, 0, 0, 4]);
+        assert!(is_compact(&result));
+    }
+
+    fn with_owned() {
+        let target = SparseVec::<_>::from_iter([4, 0, 1, 0, 8]);
+        let result = target / 2;
+        assert_eq!(result, [2, 0, 0, 0, 4]);
+        assert!(is_compact(&result));
+    }
+
+    fn with_padding_settings() {
+        // Arrange.
+        let [target, _] = padding_settings_pair();
+
+        // Act.
+        let result_ref = &target / 2.0;
+        let result_owned = target / 2.0;
+
+        // Assert.
+        for result in [result_ref, result_owned] {
+            assert!(result.retains_padding());
+            assert!(result.is_padding(&1e-12));
+        }
+    }
+
+    fn with_padding_maker() {
+        // Arrange.
+        let target = SparseVec::<f64>::new(3);
+
+        // Act.
+        let result = target / 0.0;
+
+        // Assert.
+        assert!(result.padding_val().is_nan());
+    }
+}
+
+#[test]
+fn mul() {
+    with_normal();
+    with_scalar();
+
+    fn with_normal() {
+        for [x, y] in same_len_pairs() {
+            let result = &x * &y;
+            let expected = dense_op(&x, &y, |x, y| x * y);
+            assert_eq!(result, expected);
+            assert!(is_compact(&result));
+        }
+    }
+
+    fn with_scalar() {
+        let target = SparseVecSample::normal();
+        let expected = target.iter().map(|x| x * 3).collect::<Vec<_>>();
+        assert_eq!(&target * 3, expected);
+        assert_eq!(target * 3, expected);
+    }
+}
+
+#[test]
+fn mul_assign() {
+    for [mut x, y] in same_len_pairs() {
+        let expected = dense_op(&x, &y, |x, y| x * y);
+        x *= &y;
+        assert_eq!(x, expected);
+        assert!(is_compact(&x));
+    }
+}
+
+#[test]
+fn neg() {
+    with_ref();
+    with_owned();
+
+    fn with_ref() {
+        let target = SparseVecSample::normal();
+        let result = -&target;
+        let expected = target.iter().map(|x| -x).collect::<Vec<_>>();
+        assert_eq!(result, expected);
+        assert_eq!(result.padding_ref(), &-target.padding_ref());
+    }
+
+    fn with_owned() {
+        let target = SparseVecSample::normal();
+        let expected = target.iter().map(|x| -x).collect::<Vec<_>>();
+        let result = -target;
+        assert_eq!(result, expected);
+    }
+}
+
+#[test]
+fn product() {
+    with_empty();
+    with_normal();
+
+    fn with_empty() {
+        let result = <[SparseVec<i32>; 0]>::default()
+            .iter()
+            .product::<SparseVec<_>>();
+        assert!(result.is_empty());
+        assert_eq!(result.padding_ref(), &0);
+    }
+
+    fn with_normal() {
+        let vecs = [0, 1, 2].map(SparseVecSample::random_trivals);
+        let result = vecs.iter().product::<SparseVec<_>>();
+        let expected = &(&vecs[0] * &vecs[1]) * &vecs[2];
+        assert_eq!(result, expected);
+    }
+}
+
+#[test]
+fn sub() {
+    for [x, y] in same_len_pairs() {
+        let result = &x - &y;
+        let expected = dense_op(&x, &y, |x, y| x - y);
+        assert_eq!(result, expected);
+        assert!(is_compact(&result));
+    }
+}
+
+#[test]
+fn sub_assign() {
+    for [mut x, y] in same_len_pairs() {
+        let expected = dense_op(&x, &y, |x, y| x - y);
+        x -= &y;
+        assert_eq!(x, expected);
+        assert!(is_compact(&x));
+    }
+}
+
+#[test]
+fn sum() {
+    with_empty();
+    with_normal();
+
+    fn with_empty() {
+        let result = <[SparseVec<i32>; 0]>::default()
+            .iter()
+            .sum::<SparseVec<_>>();
+        assert!(result.is_empty());
+        assert_eq!(result.padding_ref(), &0);
+    }
+
+    fn with_normal() {
+        let vecs = [0, 1, 2].map(SparseVecSample::random_trivals);
+        let result = vecs.iter().sum::<SparseVec<_>>();
+        let expected = &(&vecs[0] + &vecs[1]) + &vecs[2];
+        assert_eq!(result, expected);
+    }
+}
+
+/// Returns a pair of vectors, where only left one has padding
+/// predicate and retaining mode.
+fn padding_settings_pair() -> [SparseVec<f64>; 2] {
+    let mut x = SparseVec::<_>::with_padding_predicate(4, 0.0, |x: &f64| x.abs() < 1e-9);
+    x.set_retains_padding(true);
+    *x.edit(1) = 1.0;
+    let y = SparseVec::<_>::from_iter([0.0, 0.0, 2.0, 0.0]);
+    [x, y]
+}
+
+fn same_len_pairs() -> impl Iterator<Item = [SparseVec<i32>; 2]> {
+    SparseVecSample::pairs().filter(|[x, y]| x.len() == y.len())
+}
+
+fn dense_op(x: &SparseVec<i32>, y: &SparseVec<i32>, f: fn(i32, i32) -> i32) -> Vec<i32> {
+    x.iter().zip(y.iter()).map(|(x, y)| f(*x, *y)).collect()
+}
+
+fn is_compact(target: &SparseVec<i32>) -> bool {
+    let padding = target.padding_ref();
+    target.sparse_reader().all(|(_, x)| x != padding)
+}
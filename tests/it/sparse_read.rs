@@ -0,0 +1,197 @@
+use crate::for_test::builders::*;
+use crate::for_test::helper;
+use sparse_vector::prelude::*;
+
+#[test]
+fn as_slice() {
+    let builder = SparseSliceBuilder::new();
+    let context = &mut builder.build();
+    let expected = builder.slice_values();
+    assert_eq!(SparseRead::as_slice(context.vec()), builder.vec_values());
+    assert_eq!(SparseRead::as_slice(&context.fetch()), expected);
+    assert_eq!(SparseRead::as_slice(&context.fetch_mut()), expected);
+}
+
+#[test]
+fn is_empty() {
+    let builder = SparseSliceBuilder::new();
+    let context = &mut builder.build();
+    assert!(!SparseRead::is_empty(context.vec()));
+    assert!(!SparseRead::is_empty(&context.fetch()));
+    assert!(SparseRead::is_empty(&context.fetch().slice(..0)));
+    assert!(!SparseRead::is_empty(&context.fetch_mut()));
+}
+
+#[test]
+fn len() {
+    let builder = SparseSliceBuilder::new();
+    let context = &mut builder.build();
+    assert_eq!(SparseRead::len(context.vec()), builder.vec_values().len());
+    assert_eq!(SparseRead::len(&context.fetch()), builder.range().len());
+    assert_eq!(SparseRead::len(&context.fetch_mut()), builder.range().len());
+}
+
+#[test]
+fn padding_ref() {
+    let builder = SparseSliceBuilder::new();
+    let context = &mut builder.build();
+    assert_eq!(SparseRead::padding_ref(context.vec()), &builder.padding());
+    assert_eq!(
+        SparseRead::padding_ref(&context.fetch()),
+        &builder.padding()
+    );
+    assert_eq!(
+        SparseRead::padding_ref(&context.fetch_mut()),
+        &builder.padding()
+    );
+}
+
+#[test]
+fn to_vec() {
+    let builder = SparseSliceBuilder::new();
+    let context = &mut builder.build();
+    assert_eq!(SparseRead::to_vec(context.vec()), builder.vec_values());
+    assert_eq!(SparseRead::to_vec(&context.fetch()), builder.slice_values());
+    assert_eq!(
+        SparseRead::to_vec(&context.fetch_mut()),
+        builder.slice_values()
+    );
+}
+
+#[test]
+fn iter() {
+    let builder = SparseSliceBuilder::new();
+    let context = &mut builder.build();
+    let values = builder.slice_values();
+    assert!(SparseRead::iter(&context.fetch()).eq(&values));
+    assert!(SparseRead::iter(&context.fetch_mut()).eq(&values));
+}
+
+#[test]
+fn sparse_reader() {
+    let builder = SparseSliceBuilder::new();
+    let context = &mut builder.build();
+    let expected = collect_stored(&context.fetch());
+    assert_eq!(expected.len(), builder.npad_indexs().len());
+    assert_eq!(collect_stored(&context.fetch_mut()), expected);
+    assert_eq!(collect_stored(&context.fetch().to_sparse_vec()), expected);
+
+    fn collect_stored<S: SparseRead<Value = i32>>(target: &S) -> Vec<(usize, i32)> {
+        target.sparse_reader().map(|(i, x)| (i, *x)).collect()
+    }
+}
+
+#[test]
+fn slice() {
+    let builder = SparseSliceBuilder::new();
+    let context = &mut builder.build();
+    let len = builder.range().len();
+    let range = len / 3..len / 2;
+    let expected = builder.slice_values()[range.clone()].to_vec();
+    assert_eq!(SparseRead::slice(&context.fetch(), range.clone()), expected);
+    assert_eq!(
+        SparseRead::slice(&context.fetch_mut(), range.clone()),
+        expected
+    );
+}
+
+#[test]
+fn to_sparse_vec() {
+    let builder = SparseSliceBuilder::new();
+    let context = &mut builder.build();
+    let expected = builder.slice_values();
+    assert_eq!(SparseRead::to_sparse_vec(&context.fetch()), expected);
+    assert_eq!(SparseRead::to_sparse_vec(&context.fetch_mut()), expected);
+}
+
+#[test]
+fn get_range() {
+    let builder = SparseSliceBuilder::new();
+    let context = &mut builder.build();
+    let len = builder.range().len();
+    let expected = builder.slice_values()[1..len].to_vec();
+    let result = SparseRead::get_range(&context.fetch_mut(), 1..len).map(|x| x.to_vec());
+    assert_eq!(result, Some(expected));
+    assert!(SparseRead::get_range(&context.fetch_mut(), 1..len + 1).is_none());
+}
+
+#[test]
+fn sparse_zip() {
+    let builder = SparseSliceBuilder::new();
+    let context = &mut builder.build();
+    let other = SparseVec::<_>::from_iter(builder.slice_values());
+    let expected = collect_zip(&context.fetch(), &other);
+    assert_eq!(collect_zip(&context.fetch_mut(), &other), expected);
+
+    fn collect_zip<S: SparseRead<Value = i32>>(
+        target: &S,
+        other: &SparseVec<i32>,
+    ) -> Vec<(usize, Option<i32>, Option<i32>)> {
+        let iter = SparseRead::sparse_zip(target, &other.slice(..));
+        iter.map(|(i, x, y)| (i, x.copied(), y.copied())).collect()
+    }
+}
+
+#[test]
+fn zip_sparse() {
+    let builder = SparseSliceBuilder::new();
+    let context = &mut builder.build();
+    let other = SparseVec::<_>::from_iter(builder.slice_values());
+    let expected = context.fetch().zip_sparse(&other.slice(..)).count();
+    let target = &context.fetch_mut();
+    let result = SparseRead::zip_sparse(target, &other.slice(..)).count();
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn dense_hash() {
+    let builder = SparseSliceBuilder::new();
+    let context = &mut builder.build();
+    let expected = helper::hash(&builder.slice_values());
+    assert_eq!(helper::hash(&context.fetch().dense_hash()), expected);
+    assert_eq!(helper::hash(&context.fetch_mut().dense_hash()), expected);
+}
+
+#[test]
+fn is_sorted() {
+    let vec = &mut SparseVec::<_>::from_iter([-1, 0, 0, 2, 1]);
+    assert!(!SparseRead::is_sorted(vec));
+    assert!(SparseRead::is_sorted(&vec.slice(..4)));
+    assert!(SparseRead::is_sorted(&vec.slice_mut(..4)));
+    assert!(!SparseRead::is_sorted(&vec.slice_mut(2..)));
+}
+
+#[test]
+fn binary_search() {
+    let vec = &mut SparseVec::<_>::from_iter([-2, 0, 0, 0, 3, 5]);
+    assert_eq!(SparseRead::binary_search(vec, &3), Ok(4));
+    assert_eq!(SparseRead::binary_search(&vec.slice(1..), &3), Ok(3));
+    assert_eq!(SparseRead::binary_search(&vec.slice_mut(1..), &3), Ok(3));
+    assert_eq!(SparseRead::binary_search(&vec.slice_mut(1..), &4), Err(4));
+}
+
+#[test]
+fn binary_search_by() {
+    let vec = &mut SparseVec::<_>::from_iter([-2, 0, 0, 0, 3, 5]);
+    let target = &vec.slice_mut(1..);
+    assert_eq!(SparseRead::binary_search_by(target, |x| x.cmp(&5)), Ok(4));
+    assert_eq!(SparseRead::binary_search_by(target, |x| x.cmp(&-3)), Err(0));
+}
+
+#[test]
+fn slice_after_write() {
+    // Arrange.
+    let vec = &mut SparseVec::<_>::from_iter([1, 0, 2, 0, 3]);
+    let target = &mut vec.slice_mut(1..4);
+
+    // Act.
+    *target.edit(1) = 0;
+    *target.edit(2) = 4;
+    let result = target.slice(1..).to_vec();
+    *target.edit(0) = 5;
+
+    // Assert.
+    assert_eq!(result, vec![0, 4]);
+    assert_eq!(target.as_slice(), [5, 0, 4]);
+    assert_eq!(vec.to_vec(), vec![1, 5, 0, 4, 3]);
+}
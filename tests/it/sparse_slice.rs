@@ -1,6 +1,8 @@
+use crate::for_test::SliceContext;
 use crate::for_test::builders::*;
 use crate::for_test::helper;
 use crate::for_test::samples::*;
+use sparse_vector::prelude::*;
 use std::ops::Index;
 use test_panic::prelude::*;
 
@@ -42,6 +44,21 @@ fn to_vec() {
     assert_eq!(result, builder.slice_values());
 }
 
+#[test]
+fn to_sparse_vec() {
+    let builder = SparseSliceBuilder::new();
+    let context = builder.build();
+    let target = context.fetch();
+    let result = target.to_sparse_vec();
+    let values = builder.slice_values();
+    assert_eq!(result.to_vec(), values);
+    assert_eq!(result.padding_ref(), &builder.padding());
+    assert_eq!(
+        result.nnp(),
+        values.iter().filter(|x| **x != builder.padding()).count()
+    );
+}
+
 #[test]
 fn slice() {
     with_range_order_rev();
@@ -92,6 +109,19 @@ fn slice() {
     }
 }
 
+#[test]
+fn get_range() {
+    let builder = SparseSliceBuilder::new();
+    let context = builder.build();
+    let target = context.fetch();
+    let len = target.len();
+    assert!(target.get_range(len / 2..len + 1).is_none());
+    assert!(target.get_range(len / 2..len / 3).is_none());
+
+    let result = target.get_range(len / 3..len / 2).unwrap();
+    assert_eq!(result.to_vec(), builder.slice_values()[len / 3..len / 2]);
+}
+
 #[test]
 fn iter() {
     let builder = SparseSliceBuilder::new();
@@ -118,14 +148,444 @@ fn sparse_reader() {
     assert!(lhs.eq(rhs));
 }
 
+#[test]
+fn contains() {
+    with_normal();
+    with_dense_outside();
+
+    fn with_normal() {
+        for [xc, yc] in SparseSliceSample::pairs() {
+            for target in [xc.fetch(), yc.fetch()] {
+                let values = target.to_vec();
+                for value in -2..=2 {
+                    let result = target.contains(&value);
+                    assert_eq!(result, values.contains(&value));
+                }
+            }
+        }
+    }
+
+    fn with_dense_outside() {
+        let vec = SparseVec::<_>::from_iter([1, 2, 3, 0, 5, 6, 7, 8]);
+        assert!(vec.slice(1..5).contains(&0));
+        assert!(!vec.slice(4..8).contains(&0));
+        assert!(!vec.slice(0..3).contains(&0));
+    }
+}
+
+#[test]
+fn position() {
+    for [xc, yc] in SparseSliceSample::pairs() {
+        for target in [xc.fetch(), yc.fetch()] {
+            let values = target.to_vec();
+            for value in -2..=2 {
+                let result = target.position(|x| *x == value);
+                assert_eq!(result, values.iter().position(|x| *x == value));
+            }
+        }
+    }
+}
+
+#[test]
+fn rposition() {
+    for [xc, yc] in SparseSliceSample::pairs() {
+        for target in [xc.fetch(), yc.fetch()] {
+            let values = target.to_vec();
+            for value in -2..=2 {
+                let result = target.rposition(|x| *x == value);
+                assert_eq!(result, values.iter().rposition(|x| *x == value));
+            }
+        }
+    }
+}
+
+#[test]
+fn find_stored() {
+    // Arrange.
+    let builder = SparseSliceBuilder::new();
+    let context = builder.build();
+    let target = context.fetch();
+    let value = builder.none_padding();
+
+    // Act.
+    let result = target.find_stored(|x| *x == value);
+
+    // Assert.
+    let values = builder.slice_values();
+    let expected = values.iter().enumerate().find(|x| *x.1 == value);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn positions_of() {
+    for [xc, yc] in SparseSliceSample::pairs() {
+        for target in [xc.fetch(), yc.fetch()] {
+            let values = target.to_vec();
+            for value in -2..=2 {
+                let result = target.positions_of(&value);
+                let expected = (0..values.len()).filter(|i| values[*i] == value);
+                assert!(result.eq(expected));
+            }
+        }
+    }
+}
+
+#[test]
+fn fold_sparse() {
+    with_pairs();
+    with_retains();
+
+    fn with_pairs() {
+        for [xc, yc] in SparseSliceSample::pairs() {
+            for target in [xc.fetch(), yc.fetch()] {
+                // Act.
+                let result = target.fold_sparse(
+                    (vec![], false),
+                    |(mut acc, _), index, value| {
+                        assert_eq!(index, acc.len());
+                        acc.push(*value);
+                        (acc, false)
+                    },
+                    |(mut acc, prev_run), value, len| {
+                        assert!(!prev_run && len > 0);
+                        acc.extend(std::iter::repeat_n(*value, len));
+                        (acc, true)
+                    },
+                );
+
+                // Assert.
+                assert_eq!(result.0, target.to_vec());
+            }
+        }
+    }
+
+    fn with_retains() {
+        // Arrange.
+        let mut vec = SparseVec::<_>::new(0);
+        vec.set_retains_padding(true);
+        vec.extend([0, 1, 0, 0, 2]);
+        let target = vec.slice(..);
+
+        // Act.
+        let result = target.fold_sparse(
+            vec![],
+            |mut acc, index, value| {
+                acc.push((Some(index), *value, 1));
+                acc
+            },
+            |mut acc, value, len| {
+                acc.push((None, *value, len));
+                acc
+            },
+        );
+
+        // Assert.
+        let expected = [(None, 0, 1), (Some(1), 1, 1), (None, 0, 2), (Some(4), 2, 1)];
+        assert_eq!(result, expected);
+    }
+}
+
+#[test]
+fn value_counts() {
+    // Arrange.
+    let builder = SparseSliceBuilder::new();
+    let context = builder.build();
+    let target = context.fetch();
+
+    // Act.
+    let result = target.value_counts();
+
+    // Assert.
+    let values = builder.slice_values();
+    let mut expected = std::collections::BTreeMap::new();
+    values
+        .iter()
+        .for_each(|x| *expected.entry(x).or_insert(0) += 1);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn count_value() {
+    let builder = SparseSliceBuilder::new();
+    let context = builder.build();
+    let target = context.fetch();
+    let values = builder.slice_values();
+    for value in [builder.padding(), builder.none_padding()] {
+        let result = target.count_value(&value);
+        assert_eq!(result, values.iter().filter(|x| **x == value).count());
+    }
+}
+
+#[test]
+fn mode() {
+    with_empty();
+    with_normal();
+
+    fn with_empty() {
+        let vec = SparseVec::<i32>::new(0);
+        let result = vec.slice(..).mode();
+        assert_eq!(result, None);
+    }
+
+    fn with_normal() {
+        // Arrange.
+        let vec = SparseVec::<_>::from_iter([3, 1, 3, 0, 1, 5, 0]);
+        let target = vec.slice(1..);
+
+        // Act.
+        let result = target.mode();
+
+        // Assert.
+        assert_eq!(result, Some(&0));
+    }
+}
+
+#[test]
+fn density() {
+    let builder = SparseSliceBuilder::new();
+    let context = builder.build();
+    let target = context.fetch();
+    let result = target.density();
+    let values = builder.slice_values();
+    let nnp = values.iter().filter(|x| **x != builder.padding()).count();
+    assert_eq!(result, nnp as f64 / values.len() as f64);
+}
+
+#[test]
+fn sum() {
+    let builder = SparseSliceBuilder::new();
+    let context = builder.build();
+    let target = context.fetch();
+    let result = target.sum();
+    assert_eq!(result, builder.slice_values().iter().sum());
+}
+
+#[test]
+fn product() {
+    // Arrange.
+    let builder = SparseVecBuilder::new()
+        .set_padding(1)
+        .set_value_range(-1..=1);
+    let range = range_for(builder.len()).normal();
+    let context = SliceContext::new(builder.build(), range.clone());
+    let target = context.fetch();
+
+    // Act.
+    let result = target.product();
+
+    // Assert.
+    assert_eq!(result, builder.values()[range].iter().product());
+}
+
+#[test]
+fn dot() {
+    with_len_mismatch();
+    with_normal();
+
+    fn with_len_mismatch() {
+        let x = SparseVec::<i32>::new(3);
+        let y = SparseVec::<i32>::new(4);
+        let result = test_panic(|| x.slice(..).dot(&y.slice(..)));
+        assert!(result.is_panic());
+    }
+
+    fn with_normal() {
+        for [xc, yc] in SparseSliceSample::pairs() {
+            // Arrange.
+            let [x, y] = [xc.fetch(), yc.fetch()];
+            if x.len() != y.len() {
+                continue;
+            }
+
+            // Act.
+            let result = x.dot(&y);
+
+            // Assert.
+            let expected = x.iter().zip(y.iter()).map(|(x, y)| x * y).sum();
+            assert_eq!(result, expected);
+        }
+    }
+}
+
+#[test]
+fn min_value() {
+    with_empty();
+    with_normal();
+    with_nan();
+
+    fn with_empty() {
+        let context = SparseSliceSample::empty();
+        let target = context.fetch();
+        let result = target.min_value();
+        assert_eq!(result, None);
+    }
+
+    fn with_normal() {
+        let builder = SparseSliceBuilder::new();
+        let context = builder.build();
+        let target = context.fetch();
+        let result = target.min_value();
+        assert_eq!(result, builder.slice_values().iter().min());
+    }
+
+    fn with_nan() {
+        let vec = SparseVec::<_>::from_iter([f64::NAN, 2.0, 0.0, -1.0]);
+        let target = vec.slice(..);
+        let result = target.min_value();
+        assert_eq!(result, Some(&-1.0));
+    }
+}
+
+#[test]
+fn max_value() {
+    let builder = SparseSliceBuilder::new();
+    let context = builder.build();
+    let target = context.fetch();
+    let result = target.max_value();
+    assert_eq!(result, builder.slice_values().iter().max());
+}
+
+#[test]
+fn argmax() {
+    with_empty();
+    with_padding();
+    with_normal();
+
+    fn with_empty() {
+        let context = SparseSliceSample::empty();
+        let target = context.fetch();
+        let result = target.argmax();
+        assert_eq!(result, None);
+    }
+
+    fn with_padding() {
+        let vec = SparseVec::<_>::from_iter([-1, -2, 0, -3, 0]);
+        let target = vec.slice(1..);
+        let result = target.argmax();
+        assert_eq!(result, Some(1));
+    }
+
+    fn with_normal() {
+        // Arrange.
+        let builder = SparseSliceBuilder::new();
+        let context = builder.build();
+        let target = context.fetch();
+
+        // Act.
+        let result = target.argmax();
+
+        // Assert.
+        let values = builder.slice_values();
+        let max = values.iter().max().unwrap();
+        assert_eq!(result, values.iter().position(|x| x == max));
+    }
+}
+
+#[test]
+fn norm_l1() {
+    let context = SparseSliceSample::normal_floats();
+    let target = context.fetch();
+    let result = target.norm_l1();
+    let expected = target.iter().map(|x| x.abs()).sum::<f32>();
+    assert!((result - expected).abs() <= expected * 1e-5);
+}
+
+#[test]
+fn norm_l2() {
+    let context = SparseSliceSample::normal_floats();
+    let target = context.fetch();
+    let result = target.norm_l2();
+    let expected = target.iter().map(|x| x * x).sum::<f32>().sqrt();
+    assert!((result - expected).abs() <= expected * 1e-5);
+}
+
+#[test]
+fn norm_inf() {
+    let context = SparseSliceSample::normal_floats();
+    let target = context.fetch();
+    let result = target.norm_inf();
+    let expected = target.iter().map(|x| x.abs()).fold(0.0, f32::max);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn debug() {
+    with_normal();
+    with_alternate();
+
+    fn with_normal() {
+        // Arrange.
+        let vec = sparse_vec![len = 1_000; 2 => 1, 8 => 2, 500 => 3];
+        let context = &mut SliceContext::new(vec, 1..12);
+        let target = context.fetch();
+
+        // Act.
+        let result = format!("{target:?}");
+
+        // Assert.
+        assert_eq!(result, "SparseSlice(len=11, pad=0, {1: 1, 7: 2})");
+    }
+
+    fn with_alternate() {
+        // Arrange.
+        let vec = sparse_vec![len = 1_000; 2 => 1, 500 => 3];
+        let context = &mut SliceContext::new(vec, 1..4);
+        let target = context.fetch();
+
+        // Act.
+        let result = format!("{target:#?}");
+
+        // Assert.
+        assert_eq!(result, "SparseSlice [\n    0,\n    1,\n    0,\n]");
+    }
+}
+
+#[test]
+fn display() {
+    // Arrange.
+    let vec = sparse_vec![len = 20; 2 => 1, 8 => 2, 10 => 3];
+    let context = &mut SliceContext::new(vec, 1..12);
+    let target = context.fetch();
+
+    // Act.
+    let result = target.to_string();
+
+    // Assert.
+    assert_eq!(result, "[0, 1, 0, …(4 padding)…, 2, 0, 3, 0]");
+}
+
 #[test]
 fn hash() {
-    for [x, y] in SparseSliceSample::pairs() {
-        let target_x = x.fetch();
-        let target_y = y.fetch();
-        let result_x = helper::hash(&target_x);
-        let result_y = helper::hash(&target_y);
-        assert!(!target_x.eq(&target_y) || result_x == result_y);
+    with_pairs();
+    with_retains();
+
+    fn with_pairs() {
+        for [x, y] in SparseSliceSample::pairs() {
+            let target_x = x.fetch();
+            let target_y = y.fetch();
+            let result_x = helper::hash(&target_x);
+            let result_y = helper::hash(&target_y);
+            assert!(!target_x.eq(&target_y) || result_x == result_y);
+        }
+    }
+
+    fn with_retains() {
+        // Arrange.
+        let mut x = SparseVec::<_>::new(5);
+        x.set_retains_padding(true);
+        *x.edit(0) = 1;
+        *x.edit(1) = 0;
+        *x.edit(3) = 2;
+        let y = SparseVec::<_>::from_iter([1, 0, 0, 2, 0]);
+
+        // Act.
+        let result_x = helper::hash(&x.slice(..));
+        let result_y = helper::hash(&y.slice(..));
+
+        // Assert.
+        assert_eq!(x.nnp(), 3);
+        assert_eq!(x.slice(..), y.slice(..));
+        assert_eq!(result_x, result_y);
     }
 }
 
@@ -192,6 +652,7 @@ fn cmp() {
 fn eq() {
     with_normal();
     with_nan();
+    with_other_slice();
 
     fn with_normal() {
         for [xc, yc] in SparseSliceSample::pairs() {
@@ -219,13 +680,29 @@ fn eq() {
         *y.edit(x.len() / 2) = f32::NAN;
 
         // Act.
-        let result_xy = PartialEq::eq(x, y.slice_ref());
-        let result_yx = PartialEq::eq(y.slice_ref(), x);
+        let result_xy = PartialEq::eq(x, &y.as_slice());
+        let result_yx = PartialEq::eq(&y.as_slice(), x);
 
         // Assert.
         assert_eq!(result_xy, false);
         assert_eq!(result_yx, false);
     }
+
+    fn with_other_slice() {
+        for [xc, mut yc] in SparseSliceSample::pairs() {
+            // Arrange.
+            let expected = PartialEq::eq(&xc.fetch().to_vec(), &yc.fetch().to_vec());
+            let (x, y) = (xc.fetch(), yc.fetch_mut());
+
+            // Act.
+            let result_xy = PartialEq::eq(&x, &y);
+            let result_yx = PartialEq::eq(&y, &x);
+
+            // Assert.
+            assert_eq!(result_xy, expected);
+            assert_eq!(result_yx, expected);
+        }
+    }
 }
 
 #[test]
@@ -260,11 +737,19 @@ fn partial_cmp() {
         *y.edit(index) = f32::NAN;
 
         // Act.
-        let result_xy = PartialOrd::partial_cmp(x, y.slice_ref());
-        let result_yx = PartialOrd::partial_cmp(y.slice_ref(), x);
+        let result_xy = PartialOrd::partial_cmp(x, &y.as_slice());
+        let result_yx = PartialOrd::partial_cmp(&y.as_slice(), x);
 
         // Assert.
         assert_eq!(result_xy, None);
         assert_eq!(result_yx, None);
     }
 }
+
+#[test]
+fn from_for_sparse_vec() {
+    let builder = SparseSliceBuilder::new();
+    let context = builder.build();
+    let result = SparseVec::<_>::from(context.fetch());
+    assert_eq!(result.to_vec(), builder.slice_values());
+}
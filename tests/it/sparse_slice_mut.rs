@@ -1,6 +1,8 @@
+use crate::for_test::SliceContext;
 use crate::for_test::builders::*;
 use crate::for_test::helper;
 use crate::for_test::samples::*;
+use sparse_vector::prelude::*;
 use std::ops::Index;
 use test_panic::prelude::*;
 
@@ -43,12 +45,27 @@ fn to_vec() {
 }
 
 #[test]
-fn slice_ref() {
+fn to_sparse_vec() {
     let builder = SparseSliceBuilder::new();
     let context = &mut builder.build();
     let target = context.fetch_mut();
-    let result = target.slice_ref();
-    assert_eq!(result, &builder.build().fetch());
+    let result = target.to_sparse_vec();
+    let values = builder.slice_values();
+    assert_eq!(result.to_vec(), values);
+    assert_eq!(result.padding_ref(), &builder.padding());
+    assert_eq!(
+        result.nnp(),
+        values.iter().filter(|x| **x != builder.padding()).count()
+    );
+}
+
+#[test]
+fn as_slice() {
+    let builder = SparseSliceBuilder::new();
+    let context = &mut builder.build();
+    let target = context.fetch_mut();
+    let result = target.as_slice();
+    assert_eq!(result, builder.build().fetch());
 }
 
 #[test]
@@ -101,6 +118,19 @@ fn slice() {
     }
 }
 
+#[test]
+fn get_range() {
+    let builder = SparseSliceBuilder::new();
+    let context = &mut builder.build();
+    let target = context.fetch_mut();
+    let len = target.len();
+    assert!(target.get_range(len / 2..len + 1).is_none());
+    assert!(target.get_range(len / 2..len / 3).is_none());
+
+    let result = target.get_range(len / 3..len / 2).unwrap();
+    assert_eq!(result.to_vec(), builder.slice_values()[len / 3..len / 2]);
+}
+
 #[test]
 fn iter() {
     let builder = SparseSliceBuilder::new();
@@ -370,6 +400,460 @@ fn swap() {
     }
 }
 
+#[test]
+fn clone_from_sparse() {
+    with_len_mismatch();
+    with_same_padding();
+    with_other_padding();
+
+    fn with_len_mismatch() {
+        let context = &mut SparseSliceSample::normal();
+        let target = &mut context.fetch_mut();
+        let src = SparseVec::<i32>::new(target.len() + 1);
+        let result = test_panic(|| target.clone_from_sparse(&src.slice(..)));
+        assert!(result.is_panic());
+    }
+
+    fn with_same_padding() {
+        // Arrange.
+        let builder = SparseSliceBuilder::new();
+        let context = &mut builder.build();
+        let target = &mut context.fetch_mut();
+        let src_builder = SparseVecBuilder::new().set_seed(1).set_len(target.len());
+        let src = src_builder.set_padding(builder.padding()).build();
+
+        // Act.
+        target.clone_from_sparse(&src.slice(..));
+
+        // Assert.
+        let expected = &mut builder.vec_values();
+        expected[builder.range()].clone_from_slice(&src.to_vec());
+        assert_eq!(context.vec().to_vec(), *expected);
+        assert_eq!(
+            context.vec().nnp(),
+            expected.iter().filter(|x| **x != builder.padding()).count()
+        );
+    }
+
+    fn with_other_padding() {
+        // Arrange.
+        let builder = SparseSliceBuilder::new();
+        let context = &mut builder.build();
+        let target = &mut context.fetch_mut();
+        let src_builder = SparseVecBuilder::new().set_seed(1).set_len(target.len());
+        let src = src_builder.set_padding(builder.none_padding()).build();
+
+        // Act.
+        target.clone_from_sparse(&src.slice(..));
+
+        // Assert.
+        let expected = &mut builder.vec_values();
+        expected[builder.range()].clone_from_slice(&src.to_vec());
+        assert_eq!(context.vec().to_vec(), *expected);
+    }
+}
+
+#[test]
+fn clone_from_slice() {
+    with_len_mismatch();
+    with_normal();
+
+    fn with_len_mismatch() {
+        let context = &mut SparseSliceSample::normal();
+        let target = &mut context.fetch_mut();
+        let src = vec![0; target.len() + 1];
+        let result = test_panic(|| target.clone_from_slice(&src));
+        assert!(result.is_panic());
+    }
+
+    fn with_normal() {
+        // Arrange.
+        let builder = SparseSliceBuilder::new();
+        let context = &mut builder.build();
+        let target = &mut context.fetch_mut();
+        let src = SparseVecBuilder::new()
+            .set_seed(1)
+            .set_len(target.len())
+            .values();
+
+        // Act.
+        target.clone_from_slice(&src);
+
+        // Assert.
+        let expected = &mut builder.vec_values();
+        expected[builder.range()].clone_from_slice(&src);
+        assert_eq!(context.vec().to_vec(), *expected);
+        assert_eq!(
+            context.vec().nnp(),
+            expected.iter().filter(|x| **x != builder.padding()).count()
+        );
+    }
+}
+
+#[test]
+fn copy_from_slice() {
+    let target = &mut SparseVec::<_>::from_iter([1, 2, 3, 4, 5]);
+    target.slice_mut(1..4).copy_from_slice(&[0, 0, 7]);
+    assert_eq!(target.to_vec(), vec![1, 0, 0, 7, 5]);
+    assert_eq!(target.nnp(), 3);
+}
+
+#[test]
+fn reverse() {
+    with_normal();
+    with_stored_padding();
+
+    fn with_normal() {
+        for [mut xc, mut yc] in SparseSliceSample::pairs() {
+            for mut target in [xc.fetch_mut(), yc.fetch_mut()] {
+                // Arrange.
+                let mut expected = target.to_vec();
+                expected.reverse();
+
+                // Act.
+                target.reverse();
+
+                // Assert.
+                assert_eq!(target.to_vec(), expected);
+            }
+        }
+    }
+
+    fn with_stored_padding() {
+        // Arrange.
+        let vec = &mut stored_padding_vec();
+
+        // Act.
+        vec.slice_mut(1..5).reverse();
+
+        // Assert.
+        assert_eq!(vec.nnp(), 4);
+        assert_eq!(vec.to_vec(), [1, 0, 0, 2, 0, 3]);
+        assert!(vec.is_stored(2));
+    }
+}
+
+#[test]
+fn rotate_left() {
+    with_out_of_range();
+    with_normal();
+
+    fn with_out_of_range() {
+        let context = &mut SparseSliceSample::normal();
+        let target = &mut context.fetch_mut();
+        let mid = target.len() + 1;
+        let result = test_panic(|| target.rotate_left(mid));
+        assert!(result.is_panic());
+    }
+
+    fn with_normal() {
+        let builder = SparseSliceBuilder::new();
+        for mid in [0, 1, builder.range().len() / 2, builder.range().len()] {
+            // Arrange.
+            let context = &mut builder.build();
+            let target = &mut context.fetch_mut();
+
+            // Act.
+            target.rotate_left(mid);
+
+            // Assert.
+            let expected = &mut builder.slice_values();
+            expected.rotate_left(mid);
+            assert_eq!(target.to_vec(), *expected);
+            assert_eq!(context.vec().len(), builder.vec_values().len());
+        }
+    }
+}
+
+#[test]
+fn rotate_right() {
+    with_out_of_range();
+    with_normal();
+    with_stored_padding();
+
+    fn with_out_of_range() {
+        let context = &mut SparseSliceSample::normal();
+        let target = &mut context.fetch_mut();
+        let k = target.len() + 1;
+        let result = test_panic(|| target.rotate_right(k));
+        assert!(result.is_panic());
+    }
+
+    fn with_normal() {
+        let builder = SparseSliceBuilder::new();
+        for k in [0, 1, builder.range().len() / 2, builder.range().len()] {
+            // Arrange.
+            let context = &mut builder.build();
+            let target = &mut context.fetch_mut();
+
+            // Act.
+            target.rotate_right(k);
+
+            // Assert.
+            let expected = &mut builder.vec_values();
+            expected[builder.range()].rotate_right(k);
+            assert_eq!(context.vec().to_vec(), *expected);
+        }
+    }
+
+    fn with_stored_padding() {
+        // Arrange.
+        let vec = &mut stored_padding_vec();
+
+        // Act.
+        vec.slice_mut(1..5).rotate_right(3);
+
+        // Assert.
+        assert_eq!(vec.nnp(), 4);
+        assert_eq!(vec.to_vec(), [1, 2, 0, 0, 0, 3]);
+        assert!(vec.is_stored(2));
+    }
+}
+
+#[test]
+fn is_sorted() {
+    for [mut xc, mut yc] in SparseSliceSample::pairs() {
+        for target in [xc.fetch_mut(), yc.fetch_mut()] {
+            let result = target.is_sorted();
+            assert_eq!(result, target.to_vec().is_sorted());
+        }
+    }
+}
+
+#[test]
+fn binary_search() {
+    for [mut xc, mut yc] in SparseSliceSample::pairs() {
+        for mut target in [xc.fetch_mut(), yc.fetch_mut()] {
+            // Arrange.
+            target.sort();
+            let values = target.to_vec();
+
+            for value in -2..=2 {
+                // Act.
+                let result = target.binary_search(&value);
+
+                // Assert.
+                match values.binary_search(&value) {
+                    Ok(_) => assert_eq!(values[result.unwrap()], value),
+                    Err(x) => assert_eq!(result, Err(x)),
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn binary_search_by() {
+    // Arrange.
+    let vec = &mut SparseVec::<_>::new(1000);
+    *vec.edit(1) = -1;
+    *vec.edit(998) = 1;
+    let context = &mut SliceContext::new(vec.clone(), 1..999);
+    let target = context.fetch_mut();
+    let mut calls = 0;
+
+    // Act.
+    let result = target.binary_search_by(|x| {
+        calls += 1;
+        x.cmp(&1)
+    });
+
+    // Assert.
+    assert_eq!(result, Ok(997));
+    assert!(calls <= 3);
+}
+
+#[test]
+fn sort() {
+    for [mut xc, mut yc] in SparseSliceSample::pairs() {
+        for mut target in [xc.fetch_mut(), yc.fetch_mut()] {
+            // Arrange.
+            let mut expected = target.to_vec();
+            expected.sort();
+
+            // Act.
+            target.sort();
+
+            // Assert.
+            assert_eq!(target.to_vec(), expected);
+        }
+    }
+}
+
+#[test]
+fn sort_by() {
+    with_normal();
+    with_retained_padding();
+
+    fn with_normal() {
+        for [mut xc, mut yc] in SparseSliceSample::pairs() {
+            for mut target in [xc.fetch_mut(), yc.fetch_mut()] {
+                // Arrange.
+                let mut expected = target.to_vec();
+                expected.sort_by(|x, y| y.cmp(x));
+
+                // Act.
+                target.sort_by(|x, y| y.cmp(x));
+
+                // Assert.
+                assert_eq!(target.to_vec(), expected);
+            }
+        }
+    }
+
+    fn with_retained_padding() {
+        // Arrange.
+        let vec = &mut SparseVec::<_>::from_iter([2, 0, 0, 1, 0]);
+        vec.set_retains_padding(true);
+        *vec.edit(1) = 0;
+        let target = &mut vec.slice_mut(..);
+
+        // Act.
+        target.sort_by(|x, y| x.cmp(y));
+
+        // Assert.
+        assert_eq!(target.to_vec(), vec![0, 0, 0, 1, 2]);
+        assert!(vec.is_stored(0));
+        assert!(!vec.is_stored(1));
+    }
+}
+
+#[test]
+fn sort_by_key() {
+    // Arrange.
+    let vec = &mut SparseVec::<_>::from_iter([3, -1, 0, 2, 1, -3, 0]);
+    let target = &mut vec.slice_mut(1..);
+
+    // Act.
+    target.sort_by_key(|x: &i32| x.abs());
+
+    // Assert.
+    assert_eq!(vec.to_vec(), vec![3, 0, 0, -1, 1, 2, -3]);
+}
+
+#[test]
+fn sort_unstable() {
+    for [mut xc, mut yc] in SparseSliceSample::pairs() {
+        for mut target in [xc.fetch_mut(), yc.fetch_mut()] {
+            // Arrange.
+            let mut expected = target.to_vec();
+            expected.sort_unstable();
+
+            // Act.
+            target.sort_unstable();
+
+            // Assert.
+            assert_eq!(target.to_vec(), expected);
+        }
+    }
+}
+
+#[test]
+fn sort_unstable_by() {
+    with_normal();
+    with_retained_padding();
+
+    fn with_normal() {
+        for [mut xc, mut yc] in SparseSliceSample::pairs() {
+            for mut target in [xc.fetch_mut(), yc.fetch_mut()] {
+                // Arrange.
+                let mut expected = target.to_vec();
+                expected.sort_unstable_by(|x, y| y.cmp(x));
+
+                // Act.
+                target.sort_unstable_by(|x, y| y.cmp(x));
+
+                // Assert.
+                assert_eq!(target.to_vec(), expected);
+            }
+        }
+    }
+
+    fn with_retained_padding() {
+        // Arrange.
+        let vec = &mut SparseVec::<_>::from_iter([2, 0, 0, 1, 0]);
+        vec.set_retains_padding(true);
+        *vec.edit(1) = 0;
+        let target = &mut vec.slice_mut(..);
+
+        // Act.
+        target.sort_unstable_by(|x, y| x.cmp(y));
+
+        // Assert.
+        assert_eq!(vec.nnp(), 3);
+        assert_eq!(vec.to_vec(), vec![0, 0, 0, 1, 2]);
+    }
+}
+
+#[test]
+fn sort_unstable_by_key() {
+    for [mut xc, mut yc] in SparseSliceSample::pairs() {
+        for mut target in [xc.fetch_mut(), yc.fetch_mut()] {
+            // Arrange.
+            let mut expected = target.to_vec();
+            expected.sort_unstable_by_key(|x| x.abs());
+
+            // Act.
+            target.sort_unstable_by_key(|x| x.abs());
+
+            // Assert.
+            let result = target.to_vec();
+            assert!(
+                result
+                    .iter()
+                    .map(|x| x.abs())
+                    .eq(expected.iter().map(|x| x.abs()))
+            );
+        }
+    }
+}
+
+#[test]
+fn debug() {
+    with_normal();
+    with_alternate();
+
+    fn with_normal() {
+        // Arrange.
+        let vec = sparse_vec![len = 1_000; 2 => 1, 8 => 2, 500 => 3];
+        let context = &mut SliceContext::new(vec, 1..12);
+        let target = context.fetch_mut();
+
+        // Act.
+        let result = format!("{target:?}");
+
+        // Assert.
+        assert_eq!(result, "SparseSliceMut(len=11, pad=0, {1: 1, 7: 2})");
+    }
+
+    fn with_alternate() {
+        // Arrange.
+        let vec = sparse_vec![len = 1_000; 2 => 1, 500 => 3];
+        let context = &mut SliceContext::new(vec, 1..4);
+        let target = context.fetch_mut();
+
+        // Act.
+        let result = format!("{target:#?}");
+
+        // Assert.
+        assert_eq!(result, "SparseSliceMut [\n    0,\n    1,\n    0,\n]");
+    }
+}
+
+#[test]
+fn display() {
+    // Arrange.
+    let vec = sparse_vec![len = 20; 2 => 1, 8 => 2, 10 => 3];
+    let context = &mut SliceContext::new(vec, 1..12);
+    let target = context.fetch_mut();
+
+    // Act.
+    let result = target.to_string();
+
+    // Assert.
+    assert_eq!(result, "[0, 1, 0, …(4 padding)…, 2, 0, 3, 0]");
+}
+
 #[test]
 fn hash() {
     for [mut x, mut y] in SparseSliceSample::pairs() {
@@ -444,6 +928,7 @@ fn cmp() {
 fn eq() {
     with_normal();
     with_nan();
+    with_other_slice();
 
     fn with_normal() {
         for [mut xc, mut yc] in SparseSliceSample::pairs() {
@@ -478,6 +963,22 @@ fn eq() {
         assert_eq!(result_xy, false);
         assert_eq!(result_yx, false);
     }
+
+    fn with_other_slice() {
+        for [mut xc, yc] in SparseSliceSample::pairs() {
+            // Arrange.
+            let expected = PartialEq::eq(&xc.fetch().to_vec(), &yc.fetch().to_vec());
+            let (x, y) = (xc.fetch_mut(), yc.fetch());
+
+            // Act.
+            let result_xy = PartialEq::eq(&x, &y);
+            let result_yx = PartialEq::eq(&y, &x);
+
+            // Assert.
+            assert_eq!(result_xy, expected);
+            assert_eq!(result_yx, expected);
+        }
+    }
 }
 
 #[test]
@@ -520,3 +1021,12 @@ fn partial_cmp() {
         assert_eq!(result_yx, None);
     }
 }
+
+/// Returns `[1, 0, 2, 0, 0, 3]` with padding value stored at index 3.
+fn stored_padding_vec() -> SparseVec<i32> {
+    let mut ret = SparseVec::<_>::from_iter([1, 0, 2, 0, 0, 3]);
+    ret.set_retains_padding(true);
+    *ret.edit(3) = 0;
+    ret.set_retains_padding(false);
+    ret
+}
@@ -0,0 +1,259 @@
+use sparse_vector::{BTreeStorage, HashStorage, SparseStorage, VecStorage};
+use std::ops::Range;
+
+#[test]
+fn len() {
+    check::<BTreeStorage<i32>>();
+    check::<VecStorage<i32>>();
+    check::<HashStorage<i32>>();
+
+    fn check<S: SparseStorage<i32>>() {
+        // Arrange.
+        let target = &mut sample::<S>();
+
+        // Act.
+        let result = target.len();
+
+        // Assert.
+        assert_eq!(result, 4);
+        assert!(!target.is_empty());
+        assert!(S::default().is_empty());
+    }
+}
+
+#[test]
+fn get() {
+    check::<BTreeStorage<i32>>();
+    check::<VecStorage<i32>>();
+    check::<HashStorage<i32>>();
+
+    fn check<S: SparseStorage<i32>>() {
+        // Arrange.
+        let target = &mut sample::<S>();
+
+        // Act.
+        let results = [1, 2, 7, 9].map(|i| target.get(i).copied());
+
+        // Assert.
+        assert_eq!(results, [Some(10), None, Some(70), None]);
+        assert!(target.contains_key(3));
+        assert!(!target.contains_key(4));
+    }
+}
+
+#[test]
+fn get_mut() {
+    check::<BTreeStorage<i32>>();
+    check::<VecStorage<i32>>();
+    check::<HashStorage<i32>>();
+
+    fn check<S: SparseStorage<i32>>() {
+        // Arrange.
+        let target = &mut sample::<S>();
+
+        // Act.
+        *target.get_mut(3).unwrap() += 1;
+
+        // Assert.
+        assert_eq!(target.get(3), Some(&31));
+        assert!(target.get_mut(4).is_none());
+    }
+}
+
+#[test]
+fn insert() {
+    check::<BTreeStorage<i32>>();
+    check::<VecStorage<i32>>();
+    check::<HashStorage<i32>>();
+
+    fn check<S: SparseStorage<i32>>() {
+        // Arrange.
+        let target = &mut sample::<S>();
+
+        // Act.
+        let result_new = target.insert(4, 40);
+        let result_old = target.insert(7, 71);
+
+        // Assert.
+        assert_eq!(result_new, None);
+        assert_eq!(result_old, Some(70));
+        assert_eq!(
+            entries(target, 0..10),
+            [(1, 10), (3, 30), (4, 40), (5, 50), (7, 71)]
+        );
+    }
+}
+
+#[test]
+fn remove() {
+    check::<BTreeStorage<i32>>();
+    check::<VecStorage<i32>>();
+    check::<HashStorage<i32>>();
+
+    fn check<S: SparseStorage<i32>>() {
+        // Arrange.
+        let target = &mut sample::<S>();
+
+        // Act.
+        let result_hit = target.remove(3);
+        let result_miss = target.remove(4);
+
+        // Assert.
+        assert_eq!(result_hit, Some(30));
+        assert_eq!(result_miss, None);
+        assert_eq!(entries(target, 0..10), [(1, 10), (5, 50), (7, 70)]);
+    }
+}
+
+#[test]
+fn clear() {
+    check::<BTreeStorage<i32>>();
+    check::<VecStorage<i32>>();
+    check::<HashStorage<i32>>();
+
+    fn check<S: SparseStorage<i32>>() {
+        // Arrange.
+        let target = &mut sample::<S>();
+
+        // Act.
+        target.clear();
+
+        // Assert.
+        assert!(target.is_empty());
+    }
+}
+
+#[test]
+fn range() {
+    check::<BTreeStorage<i32>>();
+    check::<VecStorage<i32>>();
+    check::<HashStorage<i32>>();
+
+    fn check<S: SparseStorage<i32>>() {
+        // Arrange.
+        let target = &sample::<S>();
+
+        // Act.
+        let result = target.range(2..7);
+
+        // Assert.
+        assert!(result.clone().eq([(&3, &30), (&5, &50)]));
+        assert!(result.rev().eq([(&5, &50), (&3, &30)]));
+        assert_eq!(target.range(4..4).count(), 0);
+    }
+}
+
+#[test]
+fn range_mut() {
+    check::<BTreeStorage<i32>>();
+    check::<VecStorage<i32>>();
+    check::<HashStorage<i32>>();
+
+    fn check<S: SparseStorage<i32>>() {
+        // Arrange.
+        let target = &mut sample::<S>();
+
+        // Act.
+        for (_, value) in target.range_mut(2..7).rev() {
+            *value += 1;
+        }
+
+        // Assert.
+        assert_eq!(entries(target, 0..10), [(1, 10), (3, 31), (5, 51), (7, 70)]);
+    }
+}
+
+#[test]
+fn retain_range() {
+    check::<BTreeStorage<i32>>();
+    check::<VecStorage<i32>>();
+    check::<HashStorage<i32>>();
+
+    fn check<S: SparseStorage<i32>>() {
+        // Arrange.
+        let target = &mut sample::<S>();
+
+        // Act.
+        target.retain_range(2..8, |index, value| {
+            *value += 1;
+            index != 5
+        });
+
+        // Assert.
+        assert_eq!(entries(target, 0..10), [(1, 10), (3, 31), (7, 71)]);
+    }
+}
+
+#[test]
+fn drain_range() {
+    check::<BTreeStorage<i32>>();
+    check::<VecStorage<i32>>();
+    check::<HashStorage<i32>>();
+
+    fn check<S: SparseStorage<i32>>() {
+        // Arrange.
+        let target = &mut sample::<S>();
+
+        // Act.
+        let result = target.drain_range(2..7);
+
+        // Assert.
+        assert_eq!(result, [(3, 30), (5, 50)]);
+        assert_eq!(entries(target, 0..10), [(1, 10), (7, 70)]);
+    }
+}
+
+#[test]
+fn shift_keys() {
+    with_forward();
+    with_backward();
+
+    fn with_forward() {
+        check::<BTreeStorage<i32>>();
+        check::<VecStorage<i32>>();
+        check::<HashStorage<i32>>();
+
+        fn check<S: SparseStorage<i32>>() {
+            // Arrange.
+            let target = &mut sample::<S>();
+
+            // Act.
+            target.shift_keys(4, 2);
+
+            // Assert.
+            assert_eq!(entries(target, 0..10), [(1, 10), (3, 30), (7, 50), (9, 70)]);
+        }
+    }
+
+    fn with_backward() {
+        check::<BTreeStorage<i32>>();
+        check::<VecStorage<i32>>();
+        check::<HashStorage<i32>>();
+
+        fn check<S: SparseStorage<i32>>() {
+            // Arrange.
+            let target = &mut sample::<S>();
+
+            // Act.
+            target.shift_keys(5, -1);
+
+            // Assert.
+            assert_eq!(entries(target, 0..10), [(1, 10), (3, 30), (4, 50), (6, 70)]);
+        }
+    }
+}
+
+/// Returns storage with entries at 1, 3, 5 and 7.
+fn sample<S: SparseStorage<i32>>() -> S {
+    let mut ret = S::default();
+    for index in [7, 3, 5, 1] {
+        ret.insert(index, index as i32 * 10);
+    }
+
+    ret
+}
+
+/// Returns entries in range as owned values.
+fn entries<S: SparseStorage<i32>>(storage: &S, range: Range<usize>) -> Vec<(usize, i32)> {
+    storage.range(range).map(|(i, x)| (*i, *x)).collect()
+}
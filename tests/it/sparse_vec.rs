@@ -2,6 +2,7 @@ use crate::for_test::builders::*;
 use crate::for_test::helper;
 use crate::for_test::samples::*;
 use sparse_vector::prelude::*;
+use sparse_vector::{BTreeStorage, HashStorage, SparseStorage, VecStorage};
 use std::ops::Index;
 use test_panic::prelude::*;
 use upget::prelude::*;
@@ -20,12 +21,230 @@ fn with_padding() {
     let builder = SparseVecBuilder::default();
     let len = builder.len();
     let padding = builder.padding();
-    let result = SparseVec::with_padding(len, padding);
+    let result = SparseVec::<_>::with_padding(len, padding);
     assert_eq!(result.len(), len);
     assert_eq!(result.nnp(), 0);
     assert_eq!(result.padding_ref(), &padding);
 }
 
+#[test]
+fn with_padding_fn() {
+    with_maker_mismatch();
+    with_normal();
+    with_neither_default_nor_clone();
+
+    fn with_maker_mismatch() {
+        let result = test_panic(|| SparseVec::<_>::with_padding_fn(3, 42, || 0));
+        assert!(result.is_panic());
+    }
+
+    fn with_normal() {
+        let builder = SparseVecBuilder::default();
+        let len = builder.len();
+        let result = SparseVec::<_>::with_padding_fn(len, 42, || 42);
+        assert_eq!(result.len(), len);
+        assert_eq!(result.nnp(), 0);
+        assert_eq!(result.padding_ref(), &42);
+        assert_eq!(result.padding_val(), 42);
+    }
+
+    fn with_neither_default_nor_clone() {
+        // Arrange.
+        #[derive(Debug, PartialEq)]
+        struct Handle(Option<u32>);
+        let target = &mut SparseVec::<_>::with_padding_fn(3, Handle(None), || Handle(None));
+        *target.edit(0) = Handle(Some(1));
+        target.push(Handle(Some(2)));
+
+        // Act.
+        let result_pop = target.pop();
+        let result_take = target.take(0);
+
+        // Assert.
+        assert_eq!(result_pop, Some(Handle(Some(2))));
+        assert_eq!(result_take, Handle(Some(1)));
+        assert_eq!(target.pop(), Some(Handle(None)));
+        assert!(target.is_all_padding());
+    }
+}
+
+#[test]
+fn with_padding_predicate() {
+    // Arrange.
+    let target = &mut SparseVec::<_>::with_padding_predicate(3, 0.0, |x: &f64| x.abs() < 1e-9);
+
+    // Act.
+    *target.edit(0) = 1e-17;
+    *target.edit(1) = 0.5;
+    target.push(-1e-17);
+    target.extend([1e-12, 2.0]);
+
+    // Assert.
+    assert_eq!(target.len(), 6);
+    assert_eq!(target.nnp(), 2);
+    assert_eq!(target.to_vec(), vec![0.0, 0.5, 0.0, 0.0, 0.0, 2.0]);
+}
+
+#[test]
+fn into_storage() {
+    with_vec_storage();
+    with_hash_storage();
+    with_edits();
+
+    fn with_vec_storage() {
+        // Arrange.
+        let builder = SparseVecBuilder::new();
+        let target = builder.build();
+
+        // Act.
+        let result = target.into_storage::<VecStorage<_>>();
+
+        // Assert.
+        assert_eq!(result.to_vec(), builder.values());
+        assert_eq!(result.nnp(), builder.nnp());
+        assert_eq!(result.padding_ref(), &builder.padding());
+    }
+
+    fn with_hash_storage() {
+        // Arrange.
+        let builder = SparseVecBuilder::new();
+        let target = builder.build();
+
+        // Act.
+        let result = target.into_storage::<HashStorage<_>>();
+
+        // Assert.
+        assert_eq!(result.to_vec(), builder.values());
+        assert_eq!(result.nnp(), builder.nnp());
+        assert_eq!(result.padding_ref(), &builder.padding());
+    }
+
+    fn with_edits() {
+        check::<BTreeStorage<i32>>();
+        check::<VecStorage<i32>>();
+        check::<HashStorage<i32>>();
+
+        fn check<S: SparseStorage<i32>>() {
+            // Arrange.
+            let target = SparseVec::<_>::from_iter([1, 0, 3, 0, 5, 0, 7]);
+            let target = &mut target.into_storage::<S>();
+
+            // Act.
+            *target.edit(1) = 2;
+            *target.edit(2) = 0;
+            target.splice(3..5, [4, 0, 6]);
+            {
+                let writer = &mut target.sparse_writer();
+                while let Some((_, value)) = writer.next() {
+                    *value *= 10;
+                }
+            }
+
+            target.set_len(7);
+
+            // Assert.
+            assert_eq!(target.to_vec(), vec![10, 20, 0, 40, 0, 60, 0]);
+            assert!(target.iter().rev().eq(&[0, 60, 0, 40, 0, 20, 10]));
+            assert!(
+                target
+                    .sparse_reader()
+                    .eq([(0, &10), (1, &20), (3, &40), (5, &60)])
+            );
+            assert_eq!(target.nnp(), 4);
+        }
+    }
+}
+
+#[test]
+fn generic_storage() {
+    check::<BTreeStorage<i32>>();
+    check::<VecStorage<i32>>();
+    check::<HashStorage<i32>>();
+
+    fn check<S: SparseStorage<i32>>() {
+        with_constructors::<S>();
+        with_edits::<S>();
+        with_reads::<S>();
+        with_ops::<S>();
+    }
+
+    fn with_constructors<S: SparseStorage<i32>>() {
+        // Arrange.
+        let values = vec![1, 0, 3, 0, 5];
+
+        // Act.
+        let new = SparseVec::<i32, S>::new(3);
+        let with_padding = SparseVec::<i32, S>::with_padding(3, 7);
+        let from_iter = SparseVec::<i32, S>::from_iter(values.clone());
+        let collected = values.iter().copied().collect::<SparseVec<i32, S>>();
+        let from_vec = SparseVec::<i32, S>::from(values.clone());
+
+        // Assert.
+        assert_eq!(new.to_vec(), vec![0, 0, 0]);
+        assert_eq!(with_padding.to_vec(), vec![7, 7, 7]);
+        assert_eq!(from_iter.to_vec(), values);
+        assert_eq!(from_iter.nnp(), 3);
+        assert_eq!(collected.to_vec(), values);
+        assert_eq!(from_vec.to_vec(), values);
+    }
+
+    fn with_edits<S: SparseStorage<i32>>() {
+        // Arrange.
+        let mut target = SparseVec::<i32, S>::from_iter([5, 0, 3, 0, 3, 1, 0]);
+        let mut expected = vec![5, 0, 3, 0, 3, 1, 0];
+
+        // Act.
+        target.push(4);
+        target.splice(1..3, [0, 2, 2]);
+        target.reverse();
+        target.rotate_left(3);
+        target.slice_mut(2..6).sort();
+        target.map_values_in_place(|x| x * 10);
+        target.dedup();
+
+        expected.push(4);
+        expected.splice(1..3, [0, 2, 2]);
+        expected.reverse();
+        expected.rotate_left(3);
+        expected[2..6].sort();
+        expected.iter_mut().for_each(|x| *x *= 10);
+        expected.dedup();
+
+        // Assert.
+        assert_eq!(target.to_vec(), expected);
+        assert_eq!(target.nnp(), expected.iter().filter(|x| **x != 0).count());
+    }
+
+    fn with_reads<S: SparseStorage<i32>>() {
+        // Arrange.
+        let target = SparseVec::<i32, S>::from_iter([0, 1, 0, 2, 3, 0, 5]);
+        let sorted = SparseVec::<i32, S>::from_iter([0, 0, 1, 2, 3, 5]);
+
+        // Act & Assert.
+        assert!(target.contains(&3));
+        assert!(!target.contains(&4));
+        assert_eq!(sorted.binary_search(&3), Ok(4));
+        assert_eq!(sorted.binary_search(&4), Err(5));
+        assert_eq!(target.sum(), 11);
+        assert_eq!(target.dot(&target), 39);
+        assert!(target.iter().rev().eq(&[5, 0, 3, 2, 0, 1, 0]));
+        assert!(target.slice(2..5).sparse_reader().eq([(1, &2), (2, &3)]));
+    }
+
+    fn with_ops<S: SparseStorage<i32>>() {
+        // Arrange.
+        let x = SparseVec::<i32, S>::from_iter([1, 0, 3, 0, 5]);
+        let y = SparseVec::<i32, S>::from_iter([0, 2, -3, 0, 5]);
+
+        // Act.
+        let result = &x + &y;
+
+        // Assert.
+        assert_eq!(result.to_vec(), vec![1, 2, 0, 0, 10]);
+        assert_eq!(result.nnp(), 3);
+    }
+}
+
 #[test]
 fn is_empty() {
     with_zero_len();
@@ -124,6 +343,71 @@ fn to_vec() {
     assert_eq!(result, builder.values());
 }
 
+#[test]
+fn is_padding() {
+    with_normal();
+    with_predicate();
+
+    fn with_normal() {
+        let builder = SparseVecBuilder::new();
+        let target = builder.build();
+        assert!(target.is_padding(&builder.padding()));
+        assert!(!target.is_padding(&builder.none_padding()));
+    }
+
+    fn with_predicate() {
+        let target = SparseVec::<_>::with_padding_predicate(0, 0.0, |x: &f32| x.abs() < 1e-6);
+        assert!(target.is_padding(&0.0));
+        assert!(target.is_padding(&1e-9));
+        assert!(!target.is_padding(&1e-3));
+    }
+}
+
+#[test]
+fn retains_padding() {
+    let target = &mut SparseVecSample::normal();
+    assert!(!target.retains_padding());
+    target.set_retains_padding(true);
+    assert!(target.retains_padding());
+}
+
+#[test]
+fn is_stored() {
+    with_out_of_range();
+    with_normal();
+    with_padding();
+    with_retained_padding();
+
+    fn with_out_of_range() {
+        let target = SparseVecSample::normal();
+        let result = test_panic(|| target.is_stored(target.len()));
+        assert!(result.is_panic());
+    }
+
+    fn with_normal() {
+        let builder = SparseVecBuilder::new();
+        let target = builder.build();
+        let index = builder.some_npad_indexs(1)[0];
+        assert!(target.is_stored(index));
+    }
+
+    fn with_padding() {
+        let builder = SparseVecBuilder::new();
+        let target = builder.build();
+        let index = builder.some_pad_indexs(1)[0];
+        assert!(!target.is_stored(index));
+    }
+
+    fn with_retained_padding() {
+        let builder = SparseVecBuilder::new();
+        let target = &mut builder.build();
+        let index = builder.some_pad_indexs(1)[0];
+        target.set_retains_padding(true);
+        *target.edit(index) = builder.padding();
+        assert!(target.is_stored(index));
+    }
+}
+
 #[test]
 fn slice() {
     with_range_order_rev();
@@ -167,6 +451,20 @@ fn slice() {
     }
 }
 
+#[test]
+fn get_range() {
+    let builder = SparseVecBuilder::new();
+    let target = builder.build();
+    let len = builder.len();
+    assert!(target.get_range(len / 2..len + 1).is_none());
+    assert!(target.get_range(len / 2..len / 3).is_none());
+    assert!(target.get_range(..=usize::MAX).is_none());
+    assert!(target.get_range(len..).unwrap().is_empty());
+
+    let result = target.get_range(len / 3..len / 2).unwrap();
+    assert_eq!(result.to_vec(), builder.values()[len / 3..len / 2]);
+}
+
 #[test]
 fn iter() {
     let builder = SparseVecBuilder::new();
@@ -190,6 +488,313 @@ fn sparse_reader() {
     assert!(lft.eq(rgt));
 }
 
+#[test]
+fn map_values() {
+    with_normal();
+    with_padding_image();
+    with_retains();
+
+    fn with_normal() {
+        let builder = SparseVecBuilder::new();
+        let target = builder.build();
+        let result = target.map_values(|x| i64::from(*x) * 2);
+        let expected = builder.values().into_iter().map(|x| i64::from(x) * 2);
+        assert_eq!(result.padding_ref(), &(i64::from(builder.padding()) * 2));
+        assert!(result.iter().copied().eq(expected));
+    }
+
+    fn with_padding_image() {
+        let target = SparseVec::<_>::from_iter([1, 0, 2, 0, 3]);
+        let result = target.map_values(|x| x % 2);
+        assert_eq!(result.nnp(), 2);
+        assert_eq!(result.to_vec(), vec![1, 0, 0, 0, 1]);
+    }
+
+    fn with_retains() {
+        // Arrange.
+        let target = &mut SparseVec::<_>::from_iter([1, 0, 2]);
+        target.set_retains_padding(true);
+        *target.edit(1) = 0;
+
+        // Act.
+        let result = target.map_values(|x| x % 2);
+
+        // Assert.
+        assert!(result.retains_padding());
+        assert_eq!(result.nnp(), 3);
+        assert_eq!(result.to_vec(), vec![1, 0, 0]);
+    }
+}
+
+#[test]
+fn contains() {
+    with_padding();
+    with_retained_padding();
+    with_normal();
+
+    fn with_padding() {
+        let target = SparseVec::<_>::from_iter([1, 1, 1, 0]);
+        let result = target.contains(&0);
+        assert!(result);
+    }
+
+    fn with_retained_padding() {
+        let target = &mut SparseVec::<_>::from_iter([1, 1, 1]);
+        target.set_retains_padding(true);
+        *target.edit(1) = 0;
+        let result = target.contains(&0);
+        assert!(result);
+    }
+
+    fn with_normal() {
+        let builder = SparseVecBuilder::new();
+        let target = builder.build();
+        let value = builder.none_padding();
+        let result = target.contains(&value);
+        assert_eq!(result, builder.values().contains(&value));
+    }
+}
+
+#[test]
+fn position() {
+    let builder = SparseVecBuilder::new();
+    let target = builder.build();
+    let value = builder.none_padding();
+    let result = target.position(|x| *x == value);
+    assert_eq!(result, builder.values().iter().position(|x| *x == value));
+}
+
+#[test]
+fn rposition() {
+    let builder = SparseVecBuilder::new();
+    let target = builder.build();
+    let value = builder.padding();
+    let result = target.rposition(|x| *x == value);
+    assert_eq!(result, builder.values().iter().rposition(|x| *x == value));
+}
+
+#[test]
+fn find_stored() {
+    let target = SparseVec::<_>::from_iter([0, 1, 0, 2, 3]);
+    let result = target.find_stored(|x| *x > 1);
+    assert_eq!(result, Some((3, &2)));
+}
+
+#[test]
+fn positions_of() {
+    with_padding();
+    with_retained_padding();
+
+    fn with_padding() {
+        let target = SparseVec::<_>::from_iter([0, 1, 0, 0, 2]);
+        let result = target.positions_of(&0);
+        assert!(result.eq([0, 2, 3]));
+    }
+
+    fn with_retained_padding() {
+        let target = &mut SparseVec::<_>::from_iter([0, 1, 0, 2]);
+        target.set_retains_padding(true);
+        *target.edit(1) = 0;
+        let result = target.positions_of(&0);
+        assert!(result.eq([0, 1, 2]));
+    }
+}
+
+#[test]
+fn is_sorted() {
+    for [x, y] in SparseVecSample::pairs() {
+        for target in [x, y] {
+            let result = target.is_sorted();
+            assert_eq!(result, target.to_vec().is_sorted());
+        }
+    }
+}
+
+#[test]
+fn binary_search() {
+    // Arrange.
+    let target = &mut SparseVecSample::normal();
+    target.sort();
+    let values = target.to_vec();
+
+    for value in values.iter().copied().chain([i32::MIN, i32::MAX]) {
+        // Act.
+        let result = target.binary_search(&value);
+
+        // Assert.
+        match values.binary_search(&value) {
+            Ok(_) => assert_eq!(values[result.unwrap()], value),
+            Err(x) => assert_eq!(result, Err(x)),
+        }
+    }
+}
+
+#[test]
+fn fold_sparse() {
+    // Arrange.
+    let builder = SparseVecBuilder::new();
+    let target = builder.build();
+
+    // Act.
+    let result = target.fold_sparse(
+        vec![],
+        |mut acc, _, value| {
+            acc.push(*value);
+            acc
+        },
+        |mut acc, value, len| {
+            acc.extend(std::iter::repeat_n(*value, len));
+            acc
+        },
+    );
+
+    // Assert.
+    assert_eq!(result, builder.values());
+}
+
+#[test]
+fn value_counts() {
+    // Arrange.
+    let builder = SparseVecBuilder::new();
+    let target = builder.build();
+
+    // Act.
+    let result = target.value_counts();
+
+    // Assert.
+    let values = builder.values();
+    let mut expected = std::collections::BTreeMap::new();
+    values
+        .iter()
+        .for_each(|x| *expected.entry(x).or_insert(0) += 1);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn count_value() {
+    let builder = SparseVecBuilder::new();
+    let target = builder.build();
+    let values = builder.values();
+    for value in [builder.padding(), builder.none_padding()] {
+        let result = target.count_value(&value);
+        assert_eq!(result, values.iter().filter(|x| **x == value).count());
+    }
+}
+
+#[test]
+fn mode() {
+    with_empty();
+    with_normal();
+
+    fn with_empty() {
+        let vec = SparseVec::<i32>::new(0);
+        let result = vec.slice(..).mode();
+        assert_eq!(result, None);
+    }
+
+    fn with_normal() {
+        // Arrange.
+        let vec = SparseVec::<_>::from_iter([3, 1, 3, 0, 1, 5, 0]);
+        let target = vec.slice(1..);
+
+        // Act.
+        let result = target.mode();
+
+        // Assert.
+        assert_eq!(result, Some(&0));
+    }
+}
+
+#[test]
+fn density() {
+    let builder = SparseVecBuilder::new();
+    let target = builder.build();
+    let result = target.density();
+    let values = builder.values();
+    let nnp = values.iter().filter(|x| **x != builder.padding()).count();
+    assert_eq!(result, nnp as f64 / values.len() as f64);
+}
+
+#[test]
+fn sum() {
+    let builder = SparseVecBuilder::new();
+    let target = builder.build();
+    let result = target.sum();
+    assert_eq!(result, builder.values().iter().sum());
+}
+
+#[test]
+fn product() {
+    let builder = SparseVecBuilder::new()
+        .set_padding(1)
+        .set_value_range(-1..=1);
+    let target = builder.build();
+    let result = target.product();
+    assert_eq!(result, builder.values().iter().product());
+}
+
+#[test]
+fn dot() {
+    with_normal();
+    with_other_storage();
+
+    fn with_normal() {
+        for [x, y] in SparseVecSample::pairs().filter(|[x, y]| x.len() == y.len()) {
+            let result = x.dot(&y);
+            let expected = x.iter().zip(y.iter()).map(|(x, y)| x * y).sum();
+            assert_eq!(result, expected);
+        }
+    }
+
+    fn with_other_storage() {
+        for [x, y] in SparseVecSample::pairs().filter(|[x, y]| x.len() == y.len()) {
+            let expected = x.dot(&y);
+            let y = y.into_storage::<HashStorage<_>>();
+            let result = x.dot(&y);
+            assert_eq!(result, expected);
+        }
+    }
+}
+
+#[test]
+fn min_value() {
+    let builder = SparseVecBuilder::new();
+    let target = builder.build();
+    let result = target.min_value();
+    assert_eq!(result, builder.values().iter().min());
+}
+
+#[test]
+fn max_value() {
+    let builder = SparseVecBuilder::new();
+    let target = builder.build();
+    let result = target.max_value();
+    assert_eq!(result, builder.values().iter().max());
+}
+
+#[test]
+fn argmax() {
+    // Arrange.
+    let builder = SparseVecBuilder::new();
+    let target = builder.build();
+
+    // Act.
+    let result = target.argmax();
+
+    // Assert.
+    let values = builder.values();
+    let max = values.iter().max().unwrap();
+    assert_eq!(result, values.iter().position(|x| x == max));
+}
+
+#[test]
+fn norm_l2() {
+    let target = SparseVecSample::normal_floats();
+    let result = target.norm_l2();
+    let expected = target.iter().map(|x| x * x).sum::<f32>().sqrt();
+    assert!((result - expected).abs() <= expected * 1e-5);
+}
+
 #[test]
 fn set_len() {
     with_same();
@@ -251,6 +856,60 @@ fn set_len() {
     }
 }
 
+#[test]
+fn set_retains_padding() {
+    with_true();
+    with_false();
+
+    fn with_true() {
+        // Arrange.
+        let builder = SparseVecBuilder::new();
+        let target = &mut builder.build();
+        let padding = builder.padding();
+        let index = builder.some_npad_indexs(2)[0];
+        let w_index = builder.some_npad_indexs(2)[1];
+
+        // Act.
+        target.set_retains_padding(true);
+        target.push(padding);
+        target.extend([padding]);
+        *target.edit(index) = padding;
+        {
+            let w = &mut target.sparse_writer();
+            while let Some((idx, val)) = w.next() {
+                if idx == w_index {
+                    *val = padding;
+                }
+            }
+        }
+
+        // Assert.
+        let len = builder.len();
+        assert_eq!(target.nnp(), builder.nnp() + 2);
+        assert!(target.is_stored(index));
+        assert!(target.is_stored(w_index));
+        assert!(target.is_stored(len));
+        assert!(target.is_stored(len + 1));
+    }
+
+    fn with_false() {
+        // Arrange.
+        let builder = SparseVecBuilder::new();
+        let target = &mut builder.build();
+        let padding = builder.padding();
+        let index = builder.some_npad_indexs(1)[0];
+
+        // Act.
+        target.set_retains_padding(true);
+        target.set_retains_padding(false);
+        target.push(padding);
+        *target.edit(index) = padding;
+
+        // Assert.
+        assert_eq!(target.nnp(), builder.nnp() - 1);
+    }
+}
+
 #[test]
 fn slice_mut() {
     with_range_order_rev();
@@ -294,6 +953,23 @@ fn slice_mut() {
     }
 }
 
+#[test]
+fn get_range_mut() {
+    let builder = SparseVecBuilder::new();
+    let target = &mut builder.build();
+    let len = builder.len();
+    assert!(target.get_range_mut(len / 2..len + 1).is_none());
+    assert!(target.get_range_mut(len / 2..len / 3).is_none());
+
+    target
+        .get_range_mut(len / 3..len / 2)
+        .unwrap()
+        .fill(builder.padding());
+    let expected = &mut builder.values();
+    expected[len / 3..len / 2].fill(builder.padding());
+    assert_eq!(target.to_vec(), *expected);
+}
+
 #[test]
 fn sparse_writer() {
     // Arrange.
@@ -427,7 +1103,7 @@ fn pop() {
         let builder = SparseVecBuilder::new().set_padding(0);
         let value = builder.none_padding();
         let values = [builder.values(), vec![value]].concat();
-        let target = &mut SparseVec::from_iter(values);
+        let target = &mut SparseVec::<_>::from_iter(values);
         let len = target.len();
 
         // Act.
@@ -443,7 +1119,7 @@ fn pop() {
         let builder = SparseVecBuilder::new().set_padding(0);
         let padding = builder.padding();
         let values = [builder.values(), vec![padding]].concat();
-        let target = &mut SparseVec::from_iter(values);
+        let target = &mut SparseVec::<_>::from_iter(values);
         let len = target.len();
 
         // Act.
@@ -479,6 +1155,64 @@ fn push() {
     }
 }
 
+#[test]
+fn extend_from_sparse() {
+    with_same_padding();
+    with_other_padding();
+
+    fn with_same_padding() {
+        // Arrange.
+        let builder = SparseVecBuilder::new();
+        let target = &mut builder.build();
+        let src_builder = SparseSliceBuilder::new();
+        let src_context = src_builder.build();
+
+        // Act.
+        target.extend_from_sparse(&src_context.fetch());
+
+        // Assert.
+        let expected = [builder.values(), src_builder.slice_values()].concat();
+        assert_eq!(target.to_vec(), expected);
+        assert_eq!(
+            target.nnp(),
+            expected.iter().filter(|x| **x != builder.padding()).count()
+        );
+    }
+
+    fn with_other_padding() {
+        // Arrange.
+        let builder = SparseVecBuilder::new();
+        let target = &mut builder.build();
+        let src = SparseVecBuilder::new()
+            .set_padding(builder.none_padding())
+            .build();
+
+        // Act.
+        target.extend_from_sparse(&src.slice(..));
+
+        // Assert.
+        let expected = [builder.values(), src.to_vec()].concat();
+        assert_eq!(target.to_vec(), expected);
+        assert_eq!(
+            target.nnp(),
+            expected.iter().filter(|x| **x != builder.padding()).count()
+        );
+    }
+}
+
+#[test]
+fn extend_padding() {
+    let builder = SparseVecBuilder::new();
+    let target = &mut builder.build();
+    target.extend_padding(3);
+    assert_eq!(target.len(), builder.len() + 3);
+    assert_eq!(target.nnp(), builder.nnp());
+    assert_eq!(
+        target.to_vec(),
+        [builder.values(), vec![builder.padding(); 3]].concat()
+    );
+}
+
 #[test]
 fn swap() {
     with_arg1_out_of_range();
@@ -487,6 +1221,7 @@ fn swap() {
     with_padding_and_padding();
     with_padding_and_value();
     with_value_and_value();
+    with_retained_padding();
 
     fn with_arg1_out_of_range() {
         // Arrange.
@@ -574,6 +1309,24 @@ fn swap() {
         rhs.swap(idx_x, idx_y);
         assert_eq!(target.to_vec(), *rhs);
     }
+
+    fn with_retained_padding() {
+        // Arrange.
+        let builder = SparseVecBuilder::new();
+        let target = &mut builder.build();
+        let idx_x = builder.some_pad_indexs(2)[0];
+        let idx_y = builder.some_pad_indexs(2)[1];
+        target.set_retains_padding(true);
+        *target.edit(idx_x) = builder.padding();
+
+        // Act.
+        target.swap(idx_x, idx_y);
+
+        // Assert.
+        assert!(!target.is_stored(idx_x));
+        assert!(target.is_stored(idx_y));
+        assert_eq!(target.to_vec(), builder.values());
+    }
 }
 
 #[test]
@@ -613,6 +1366,274 @@ fn fill_with() {
     assert_eq!(target.to_vec(), *rhs);
 }
 
+#[test]
+fn copy_within() {
+    with_dest_out_of_range();
+    with_normal();
+
+    fn with_dest_out_of_range() {
+        let builder = SparseVecBuilder::new();
+        let target = &mut builder.build();
+        let dest = builder.len() / 2 + 1;
+        let result = test_panic(|| target.copy_within(..builder.len() / 2, dest));
+        assert!(result.is_panic());
+    }
+
+    fn with_normal() {
+        let builder = SparseVecBuilder::new();
+        let len = builder.len();
+        for (src, dest) in [
+            (0..len / 2, len / 2),
+            (len / 3..len, 0),
+            (len / 4..len / 2, len / 3),
+        ] {
+            // Arrange.
+            let target = &mut builder.build();
+
+            // Act.
+            target.copy_within(src.clone(), dest);
+
+            // Assert.
+            let expected = &mut builder.values();
+            expected.copy_within(src, dest);
+            assert_eq!(target.to_vec(), *expected);
+            assert_eq!(
+                target.nnp(),
+                expected.iter().filter(|x| **x != builder.padding()).count()
+            );
+        }
+    }
+}
+
+#[test]
+fn reverse() {
+    let builder = SparseVecBuilder::new();
+    let target = &mut builder.build();
+    target.reverse();
+    let expected = &mut builder.values();
+    expected.reverse();
+    assert_eq!(target.to_vec(), *expected);
+}
+
+#[test]
+fn rotate_left() {
+    let builder = SparseVecBuilder::new();
+    let target = &mut builder.build();
+    target.rotate_left(builder.len() / 3);
+    let expected = &mut builder.values();
+    expected.rotate_left(builder.len() / 3);
+    assert_eq!(target.to_vec(), *expected);
+}
+
+#[test]
+fn rotate_right() {
+    let builder = SparseVecBuilder::new();
+    let target = &mut builder.build();
+    target.rotate_right(builder.len() / 3);
+    let expected = &mut builder.values();
+    expected.rotate_right(builder.len() / 3);
+    assert_eq!(target.to_vec(), *expected);
+}
+
+#[test]
+fn sort() {
+    let builder = SparseVecBuilder::new();
+    let target = &mut builder.build();
+    target.sort();
+    let expected = &mut builder.values();
+    expected.sort();
+    assert_eq!(target.to_vec(), *expected);
+}
+
+#[test]
+fn sort_by_key() {
+    let target = &mut SparseVec::<_>::from_iter([3, -1, 0, 2, 1, -3, 0]);
+    target.sort_by_key(|x: &i32| -x.abs());
+    assert_eq!(target.to_vec(), vec![3, -3, 2, -1, 1, 0, 0]);
+}
+
+#[test]
+fn dedup() {
+    for builder in [
+        SparseVecBuilder::new(),
+        SparseVecBuilder::new().set_value_range(1..=2),
+    ] {
+        // Arrange.
+        let target = &mut builder.build();
+
+        // Act.
+        target.dedup();
+
+        // Assert.
+        let expected = &mut builder.values();
+        expected.dedup();
+        assert_eq!(target.len(), expected.len());
+        assert_eq!(target.to_vec(), *expected);
+    }
+}
+
+#[test]
+fn dedup_by_key() {
+    let target = &mut SparseVec::<_>::from_iter([0, 1, -1, 0, 0, 2, 0, -2, 2]);
+    target.dedup_by_key(|x: &i32| x.abs());
+    assert_eq!(target.to_vec(), vec![0, 1, 0, 2, 0, -2]);
+}
+
+#[test]
+fn dedup_by() {
+    with_padding_never_same();
+    with_normal();
+
+    fn with_padding_never_same() {
+        let target = &mut SparseVec::<_>::from_iter([1, 0, 0, 0, 2, 2]);
+        target.dedup_by(|x, y| *x != 0 && x == y);
+        assert_eq!(target.to_vec(), vec![1, 0, 0, 0, 2]);
+    }
+
+    fn with_normal() {
+        let target = &mut SparseVec::<_>::from_iter([0, 0, 1, 0, 0, 3, 5, 0, 4]);
+        target.dedup_by(|x, y| x % 2 == y % 2);
+        assert_eq!(target.to_vec(), vec![0, 1, 0, 3, 0]);
+    }
+}
+
+#[test]
+fn compact() {
+    // Arrange.
+    let builder = SparseVecBuilder::new();
+    let target = &mut SparseVec::<_>::with_padding(0, builder.padding());
+    target.set_retains_padding(true);
+    target.extend(builder.values());
+
+    // Act.
+    target.compact();
+
+    // Assert.
+    assert_eq!(target.nnp(), builder.nnp());
+    assert_eq!(target.to_vec(), builder.values());
+}
+
+#[test]
+fn prune() {
+    // Arrange.
+    let values = [1.0, 1e-17, 0.0, -1e-17, -1.0, 1e-3];
+    let target = &mut SparseVec::<f64>::with_padding(0, 0.0);
+    target.extend(values);
+
+    // Act.
+    target.prune(1e-9);
+
+    // Assert.
+    assert_eq!(target.nnp(), 3);
+    assert_eq!(target.to_vec(), vec![1.0, 0.0, 0.0, 0.0, -1.0, 1e-3]);
+}
+
+#[test]
+fn map_values_in_place() {
+    with_normal();
+    with_padding_image();
+    with_predicate_kept();
+    with_predicate_dropped();
+    with_retains();
+    with_maker_kept();
+    with_maker_mismatch();
+    with_default_maker_mismatch();
+
+    fn with_normal() {
+        let builder = SparseVecBuilder::new();
+        let target = &mut builder.build();
+        target.map_values_in_place(|x| x * 2);
+        let expected = builder.values().iter().map(|x| x * 2).collect::<Vec<_>>();
+        assert_eq!(target.padding_ref(), &(builder.padding() * 2));
+        assert_eq!(target.to_vec(), expected);
+    }
+
+    fn with_padding_image() {
+        let target = &mut SparseVec::<_>::from_iter([1, 0, 2, 0, 3]);
+        target.map_values_in_place(|x| x % 2);
+        assert_eq!(target.nnp(), 2);
+        assert_eq!(target.to_vec(), vec![1, 0, 0, 0, 1]);
+    }
+
+    fn with_predicate_kept() {
+        // Arrange.
+        let target = &mut SparseVec::<_>::with_padding_predicate(3, 0.0, |x: &f64| x.abs() < 1e-9);
+        *target.edit(0) = 1.0;
+
+        // Act.
+        target.map_values_in_place(|x| x * 2.0);
+        *target.edit(1) = 1e-12;
+
+        // Assert.
+        assert!(target.is_padding(&1e-12));
+        assert_eq!(target.nnp(), 1);
+        assert_eq!(target.to_vec(), vec![2.0, 0.0, 0.0]);
+    }
+
+    fn with_predicate_dropped() {
+        // Arrange.
+        let target = &mut SparseVec::<_>::with_padding_predicate(3, 0.0, |x: &f64| x.abs() < 1e-9);
+        *target.edit(0) = 1.0;
+
+        // Act.
+        target.map_values_in_place(|x| x + 1.0);
+
+        // Assert.
+        assert!(target.is_padding(&1.0));
+        assert!(!target.is_padding(&1e-12));
+        assert_eq!(target.to_vec(), vec![2.0, 1.0, 1.0]);
+    }
+
+    fn with_retains() {
+        // Arrange.
+        let target = &mut SparseVec::<_>::from_iter([1, 2, 3]);
+        target.set_retains_padding(true);
+
+        // Act.
+        target.map_values_in_place(|x| x % 2);
+
+        // Assert.
+        assert!(target.retains_padding());
+        assert_eq!(target.nnp(), 3);
+        assert_eq!(target.to_vec(), vec![1, 0, 1]);
+    }
+
+    fn with_maker_kept() {
+        // Arrange.
+        let target = &mut SparseVec::<_>::with_padding_fn(3, vec![], Vec::<u8>::new);
+        *target.edit(1) = vec![1];
+
+        // Act.
+        target.map_values_in_place(|x| x.iter().map(|x| x * 2).collect());
+
+        // Assert.
+        assert_eq!(target.padding_val(), Vec::<u8>::new());
+        assert_eq!(target.to_vec(), vec![vec![], vec![2], vec![]]);
+    }
+
+    fn with_maker_mismatch() {
+        // Arrange.
+        let target = &mut SparseVec::<_>::with_padding_fn(3, vec![], Vec::<u8>::new);
+        *target.edit(1) = vec![1];
+
+        // Act.
+        target.map_values_in_place(|x| x.iter().chain(&[0]).copied().collect());
+        let taken = target.take(1);
+
+        // Assert.
+        assert_eq!(taken, vec![1, 0]);
+        assert_eq!(target.padding_val(), vec![0]);
+        assert_eq!(target.to_vec(), vec![vec![0], vec![0], vec![0]]);
+    }
+
+    fn with_default_maker_mismatch() {
+        let target = &mut SparseVec::<f64>::new(3);
+        target.map_values_in_place(|x| x + 1.0);
+        assert_eq!(target.padding_val(), 1.0);
+        assert_eq!(target.to_vec(), vec![1.0, 1.0, 1.0]);
+    }
+}
+
 #[test]
 fn splice() {
     with_range_order_rev();
@@ -655,6 +1676,35 @@ fn splice() {
             x.splice(range.clone(), inserts);
         });
         assert_eq!(target.to_vec(), rhs);
+        assert_eq!(
+            target.nnp(),
+            rhs.iter().filter(|&&x| x != builder.padding()).count()
+        );
+    }
+}
+
+#[test]
+fn debug() {
+    with_normal();
+    with_alternate_short();
+    with_alternate_long();
+
+    fn with_normal() {
+        let target = sparse_vec![len = 1_000_000; 3 => 1, 50 => 2];
+        let result = format!("{target:?}");
+        assert_eq!(result, "SparseVec(len=1000000, pad=0, {3: 1, 50: 2})");
+    }
+
+    fn with_alternate_short() {
+        let target = sparse_vec![1, 0, 3];
+        let result = format!("{target:#?}");
+        assert_eq!(result, "SparseVec [\n    1,\n    0,\n    3,\n]");
+    }
+
+    fn with_alternate_long() {
+        let target = sparse_vec![len = 100, pad = 7; 3 => 1];
+        let result = format!("{target:#?}");
+        assert_eq!(result, "SparseVec(len=100, pad=7, {\n    3: 1,\n})");
     }
 }
 
@@ -665,10 +1715,44 @@ fn default() {
     assert_eq!(result.padding_ref(), &0);
 }
 
+#[test]
+fn display() {
+    with_empty();
+    with_short_runs();
+    with_long_runs();
+    with_format_options();
+
+    fn with_empty() {
+        let target = SparseVec::<i32>::default();
+        let result = target.to_string();
+        assert_eq!(result, "[]");
+    }
+
+    fn with_short_runs() {
+        let target = sparse_vec![0, 1, 0, 0, 2, 0, 0, 0];
+        let result = target.to_string();
+        assert_eq!(result, "[0, 1, 0, 0, 2, 0, 0, 0]");
+    }
+
+    fn with_long_runs() {
+        let target = sparse_vec![len = 1_000_000; 0 => 1, 999_999 => 2];
+        let result = target.to_string();
+        assert_eq!(result, "[1, 0, …(999997 padding)…, 2]");
+    }
+
+    fn with_format_options() {
+        let target = sparse_vec![len = 6; 1 => 0.5];
+        let result = format!("{target:.1}");
+        assert_eq!(result, "[0.0, 0.5, 0.0, …(3 padding)…]");
+    }
+}
+
 #[test]
 fn extend() {
     with_value();
     with_ref();
+    with_entries();
+    with_entries_index_max();
 
     fn with_value() {
         let builder = SparseVecBuilder::new();
@@ -691,6 +1775,38 @@ fn extend() {
         let rhs = sample_vec.iter().chain(vec);
         assert!(target.iter().eq(rhs));
     }
+
+    fn with_entries() {
+        let builder = SparseVecBuilder::new();
+        let target = &mut builder.build();
+        let len = builder.len();
+        let entries = [
+            (0, builder.padding()),
+            (len / 2, builder.none_padding()),
+            (len + 2, 1),
+        ];
+        target.extend(entries);
+
+        let expected = &mut builder.values();
+        expected.resize(len + 3, builder.padding());
+        for (index, value) in entries {
+            expected[index] = value;
+        }
+
+        assert_eq!(target.to_vec(), *expected);
+    }
+
+    fn with_entries_index_max() {
+        // Arrange.
+        let target = &mut SparseVec::<_>::from_iter([1, 2, 3]);
+
+        // Act.
+        let result = test_panic(|| target.extend([(1, 0), (usize::MAX, 4), (2, 0)]));
+
+        // Assert.
+        assert!(result.is_panic());
+        assert_eq!(target.to_vec(), vec![1, 0, 3]);
+    }
 }
 
 #[test]
@@ -700,13 +1816,13 @@ fn from() {
 
     fn with_arr() {
         let arr = ValuesBuilder::new().array();
-        let result = SparseVec::from(arr.clone());
+        let result = SparseVec::<_>::from(arr.clone());
         assert!(result.iter().eq(arr.iter()));
     }
 
     fn with_vec() {
         let vec = ValuesBuilder::new().values();
-        let result = SparseVec::from(vec.clone());
+        let result = SparseVec::<_>::from(vec.clone());
         assert!(result.iter().eq(vec.iter()));
     }
 }
@@ -716,7 +1832,7 @@ fn from_iter() {
     let builder = SparseVecBuilder::new();
     let vec = builder.build();
     let iter = vec.iter().cloned();
-    let result = SparseVec::from_iter(iter);
+    let result = SparseVec::<_>::from_iter(iter);
     assert_eq!(result.len(), builder.len());
     assert!(result.iter().eq(vec.iter()));
 }
@@ -818,6 +1934,9 @@ fn cmp() {
 fn eq() {
     with_normal();
     with_nan();
+    with_other_type();
+    with_sparse_slices();
+    with_dense();
 
     fn with_normal() {
         for pair in SparseVecSample::pairs() {
@@ -850,12 +1969,62 @@ fn eq() {
         assert_eq!(result_xy, false);
         assert_eq!(result_yx, false);
     }
+
+    fn with_other_type() {
+        let x = sparse_vec![String::from("a"), String::new(), String::from("c")];
+        let y = sparse_vec!["a", "", "c"];
+        let z = sparse_vec!["a", "b", "c"];
+        assert!(x == y);
+        assert!(x != z);
+    }
+
+    fn with_sparse_slices() {
+        for pair in SparseVecSample::pairs() {
+            // Arrange.
+            let [x, mut y] = pair;
+            let expected = PartialEq::eq(&x.to_vec(), &y.to_vec());
+
+            // Act.
+            let result_slice = PartialEq::eq(&x, &y.slice(..));
+            let result_slice_mut = PartialEq::eq(&x, &y.slice_mut(..));
+
+            // Assert.
+            assert_eq!(result_slice, expected);
+            assert_eq!(result_slice_mut, expected);
+        }
+    }
+
+    fn with_dense() {
+        for pair in SparseVecSample::pairs() {
+            // Arrange.
+            let [x, y] = pair;
+            let y = y.to_vec();
+            let expected = PartialEq::eq(&x.to_vec(), &y);
+
+            // Act.
+            let result_vec = PartialEq::eq(&x, &y);
+            let result_slice = PartialEq::eq(&x, y.as_slice());
+            let result_rev = PartialEq::eq(&y, &x);
+
+            // Assert.
+            assert_eq!(result_vec, expected);
+            assert_eq!(result_slice, expected);
+            assert_eq!(result_rev, expected);
+        }
+
+        let target = sparse_vec![1, 0, 3];
+        assert!(target == [1, 0, 3]);
+        assert!([1, 0, 3] == target);
+        assert!(target != [1, 0]);
+    }
 }
 
 #[test]
 fn partial_cmp() {
     with_normal();
     with_nan();
+    with_sparse_slices();
+    with_dense();
 
     fn with_normal() {
         for pair in SparseVecSample::pairs() {
@@ -889,6 +2058,40 @@ fn partial_cmp() {
         assert_eq!(result_xy, None);
         assert_eq!(result_yx, None);
     }
+
+    fn with_sparse_slices() {
+        for pair in SparseVecSample::pairs() {
+            // Arrange.
+            let [x, mut y] = pair;
+            let expected = PartialOrd::partial_cmp(&x.to_vec(), &y.to_vec());
+
+            // Act.
+            let result_slice = PartialOrd::partial_cmp(&x, &y.slice(..));
+            let result_slice_mut = PartialOrd::partial_cmp(&x, &y.slice_mut(..));
+
+            // Assert.
+            assert_eq!(result_slice, expected);
+            assert_eq!(result_slice_mut, expected);
+        }
+    }
+
+    fn with_dense() {
+        for pair in SparseVecSample::pairs() {
+            // Arrange.
+            let [x, y] = pair;
+            let y = y.to_vec();
+            let expected_xy = PartialOrd::partial_cmp(&x.to_vec(), &y);
+            let expected_yx = PartialOrd::partial_cmp(&y, &x.to_vec());
+
+            // Act.
+            let result_xy = PartialOrd::partial_cmp(&x, &y);
+            let result_yx = PartialOrd::partial_cmp(&y, &x);
+
+            // Assert.
+            assert_eq!(result_xy, expected_xy);
+            assert_eq!(result_yx, expected_yx);
+        }
+    }
 }
 
 #[test]
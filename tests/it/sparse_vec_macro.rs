@@ -0,0 +1,61 @@
+use crate::for_test::builders::*;
+use sparse_vector::prelude::*;
+use test_panic::prelude::*;
+
+#[test]
+fn dense_form() {
+    with_empty();
+    with_normal();
+    with_trailing_comma();
+
+    fn with_empty() {
+        let result: SparseVec<i32> = sparse_vec![];
+        assert!(result.is_empty());
+    }
+
+    fn with_normal() {
+        let result = sparse_vec![1, 0, 3, 0, 5];
+        assert_eq!(result.to_vec(), vec![1, 0, 3, 0, 5]);
+        assert_eq!(result.padding_ref(), &0);
+        assert_eq!(result.nnp(), 3);
+    }
+
+    fn with_trailing_comma() {
+        let result = sparse_vec![1, 0, 3,];
+        assert_eq!(result.to_vec(), vec![1, 0, 3]);
+    }
+}
+
+#[test]
+fn repeat_form() {
+    let builder = SparseVecBuilder::new();
+    let result = sparse_vec![builder.padding(); builder.len()];
+    assert_eq!(result.len(), builder.len());
+    assert_eq!(result.nnp(), 0);
+    assert_eq!(result.padding_ref(), &builder.padding());
+}
+
+#[test]
+fn sparse_form() {
+    with_padding();
+    with_default_padding();
+    with_out_of_range();
+
+    fn with_padding() {
+        let result = sparse_vec![len = 5, pad = 7; 1 => 1, 3 => 2];
+        assert_eq!(result.to_vec(), vec![7, 1, 7, 2, 7]);
+        assert_eq!(result.padding_ref(), &7);
+        assert_eq!(result.nnp(), 2);
+    }
+
+    fn with_default_padding() {
+        let result = sparse_vec![len = 5; 0 => 1, 4 => 2,];
+        assert_eq!(result.to_vec(), vec![1, 0, 0, 0, 2]);
+        assert_eq!(result.padding_ref(), &0);
+    }
+
+    fn with_out_of_range() {
+        let result = test_panic(|| sparse_vec![len = 5; 5 => 1]);
+        assert!(result.is_panic());
+    }
+}
@@ -0,0 +1,91 @@
+use crate::for_test::builders::*;
+use sparse_vector::BTreeStorage;
+use sparse_vector::prelude::*;
+
+#[test]
+fn edit() {
+    with_vec();
+    with_slice_mut();
+
+    fn with_vec() {
+        let builder = SparseVecBuilder::new();
+        let target = &mut builder.build();
+        *SparseWrite::edit(target, 0) = builder.none_padding();
+        assert_eq!(target[0], builder.none_padding());
+    }
+
+    fn with_slice_mut() {
+        let builder = SparseSliceBuilder::new();
+        let context = &mut builder.build();
+        *SparseWrite::edit(&mut context.fetch_mut(), 0) = builder.none_padding();
+        assert_eq!(context.vec()[builder.range().start], builder.none_padding());
+    }
+}
+
+#[test]
+fn take() {
+    let builder = SparseSliceBuilder::new();
+    let context = &mut builder.build();
+    let result = SparseWrite::take(&mut context.fetch_mut(), 0);
+    assert_eq!(result, builder.slice_values()[0]);
+    assert_eq!(context.vec()[builder.range().start], builder.padding());
+}
+
+#[test]
+fn swap() {
+    let builder = SparseSliceBuilder::new();
+    let context = &mut builder.build();
+    let last = builder.range().len() - 1;
+    SparseWrite::swap(&mut context.fetch_mut(), 0, last);
+
+    let expected = &mut builder.slice_values();
+    expected.swap(0, last);
+    assert_eq!(context.fetch().to_vec(), *expected);
+}
+
+#[test]
+fn fill() {
+    with_default();
+    with_vec();
+
+    fn with_default() {
+        let target = &mut Wrapper(SparseVec::<_>::from_iter([1, 0, 2]));
+        target.fill(3);
+        assert_eq!(target.0.to_vec(), vec![3, 3, 3]);
+    }
+
+    fn with_vec() {
+        let builder = SparseVecBuilder::new();
+        let target = &mut builder.build();
+        SparseWrite::fill(target, builder.padding());
+        assert!(target.is_all_padding());
+    }
+}
+
+/// Implementor relying on provided methods.
+struct Wrapper(SparseVec<i32>);
+
+impl SparseRead for Wrapper {
+    type Value = i32;
+    type Storage = BTreeStorage<i32>;
+
+    fn as_slice(&self) -> SparseSlice<'_, i32> {
+        self.0.slice(..)
+    }
+}
+
+impl SparseWrite for Wrapper {
+    type Editor<'s> = <SparseVec<i32> as SparseWrite>::Editor<'s>;
+
+    fn edit(&mut self, index: usize) -> Self::Editor<'_> {
+        self.0.edit(index)
+    }
+
+    fn take(&mut self, index: usize) -> i32 {
+        self.0.take(index)
+    }
+
+    fn swap(&mut self, x: usize, y: usize) {
+        self.0.swap(x, y);
+    }
+}
@@ -0,0 +1,195 @@
+use crate::for_test::samples::*;
+use sparse_vector::prelude::*;
+
+#[test]
+fn paddings() {
+    let x = SparseVec::<_>::with_padding(3, 1);
+    let y = SparseVec::<_>::with_padding(5, 2);
+    let target = x.sparse_zip(&y);
+    let result = target.paddings();
+    assert_eq!(result, (&1, &2));
+}
+
+#[test]
+fn into_items() {
+    with_forward();
+    with_backward();
+    with_index();
+
+    fn with_forward() {
+        for [x, y] in SparseVecSample::pairs() {
+            let target = x.sparse_zip(&y).into_items();
+            let result = target.collect::<Vec<_>>();
+            assert_eq!(result, expected_items(&x, &y));
+        }
+    }
+
+    fn with_backward() {
+        for [x, y] in SparseVecSample::pairs() {
+            let target = x.sparse_zip(&y).into_items();
+            let result = target.rev().collect::<Vec<_>>();
+            assert!(
+                result
+                    .into_iter()
+                    .eq(expected_items(&x, &y).into_iter().rev())
+            );
+        }
+    }
+
+    fn with_index() {
+        for [x, y] in SparseVecSample::pairs() {
+            let target = x.sparse_zip(&y).into_items();
+            let result = target.map(|x| x.index()).collect::<Vec<_>>();
+            let expected = x.sparse_zip(&y).map(|x| x.0).collect::<Vec<_>>();
+            assert_eq!(result, expected);
+        }
+    }
+
+    fn expected_items<'a>(
+        x: &'a SparseVec<i32>,
+        y: &'a SparseVec<i32>,
+    ) -> Vec<ZipItem<'a, i32, i32>> {
+        let elms = expected(x, y).into_iter().map(|x| match x {
+            (i, Some(x), Some(y)) => ZipItem::Both(i, x, y),
+            (i, Some(x), None) => ZipItem::Left(i, x),
+            (i, None, Some(y)) => ZipItem::Right(i, y),
+            (_, None, None) => unreachable!(),
+        });
+
+        elms.collect()
+    }
+}
+
+#[test]
+fn zip_sparse() {
+    with_vec();
+    with_slice();
+    with_slice_mut();
+
+    fn with_vec() {
+        for [x, y] in SparseVecSample::pairs() {
+            let result = x.zip_sparse(&y).collect::<Vec<_>>();
+            let expected = x.sparse_zip(&y).into_items().collect::<Vec<_>>();
+            assert_eq!(result, expected);
+        }
+    }
+
+    fn with_slice() {
+        let x = sparse_vec![len = 10; 1 => 1, 3 => 3, 8 => 8];
+        let y = sparse_vec![len = 10; 3 => 4, 4 => 5, 9 => 9];
+        let (sx, sy) = (x.slice(1..9), y.slice(2..8));
+        let result = sx.zip_sparse(&sy).collect::<Vec<_>>();
+        let expected = [
+            ZipItem::Left(0, &1),
+            ZipItem::Right(1, &4),
+            ZipItem::Both(2, &3, &5),
+        ];
+        assert_eq!(result, expected);
+    }
+
+    fn with_slice_mut() {
+        let mut x = sparse_vec![len = 10; 1 => 1, 3 => 3, 8 => 8];
+        let y = sparse_vec![len = 10; 3 => 4, 4 => 5, 9 => 9];
+        let (sx, sy) = (x.slice_mut(1..9), y.slice(2..8));
+        let result = sx.zip_sparse(&sy).collect::<Vec<_>>();
+        let expected = [
+            ZipItem::Left(0, &1),
+            ZipItem::Right(1, &4),
+            ZipItem::Both(2, &3, &5),
+        ];
+        assert_eq!(result, expected);
+    }
+}
+
+#[test]
+fn next() {
+    with_normal();
+    with_slice();
+
+    fn with_normal() {
+        for [x, y] in SparseVecSample::pairs() {
+            let target = x.sparse_zip(&y);
+            let result = target.collect::<Vec<_>>();
+            assert_eq!(result, expected(&x, &y));
+        }
+    }
+
+    fn with_slice() {
+        // Arrange.
+        let x = sparse_vec![len = 10; 1 => 1, 3 => 3, 8 => 8];
+        let y = sparse_vec![len = 10; 3 => 4, 4 => 5, 9 => 9];
+        let (sx, sy) = (x.slice(1..9), y.slice(2..8));
+        let target = sx.sparse_zip(&sy);
+
+        // Act.
+        let result = target.collect::<Vec<_>>();
+
+        // Assert.
+        let expected = [
+            (0, Some(&1), None),
+            (1, None, Some(&4)),
+            (2, Some(&3), Some(&5)),
+        ];
+        assert_eq!(result, expected);
+    }
+}
+
+#[test]
+fn next_back() {
+    for [x, y] in SparseVecSample::pairs() {
+        let target = x.sparse_zip(&y);
+        let result = target.rev().collect::<Vec<_>>();
+        assert!(result.into_iter().eq(expected(&x, &y).into_iter().rev()));
+    }
+}
+
+#[test]
+fn next_both_ends() {
+    for [x, y] in SparseVecSample::pairs() {
+        // Arrange.
+        let target = &mut x.sparse_zip(&y);
+        let mut result = (Vec::new(), Vec::new());
+
+        // Act.
+        for i in 0.. {
+            let item = if i % 3 == 0 {
+                target.next_back()
+            } else {
+                target.next()
+            };
+            match (i % 3 == 0, item) {
+                (_, None) => break,
+                (true, Some(x)) => result.1.push(x),
+                (false, Some(x)) => result.0.push(x),
+            }
+        }
+
+        // Assert.
+        result.0.extend(result.1.into_iter().rev());
+        assert_eq!(result.0, expected(&x, &y));
+    }
+}
+
+#[test]
+fn size_hint() {
+    for [x, y] in SparseVecSample::pairs() {
+        let target = x.sparse_zip(&y);
+        let result = target.size_hint();
+        let len = expected(&x, &y).len();
+        assert!(result.0 <= len && len <= result.1.unwrap());
+    }
+}
+
+fn expected<'a>(
+    x: &'a SparseVec<i32>,
+    y: &'a SparseVec<i32>,
+) -> Vec<(usize, Option<&'a i32>, Option<&'a i32>)> {
+    let len = usize::min(x.len(), y.len());
+    let elms = (0..len).map(|i| {
+        let x_value = x.is_stored(i).then_some(&x[i]);
+        let y_value = y.is_stored(i).then_some(&y[i]);
+        (i, x_value, y_value)
+    });
+
+    elms.filter(|x| x.1.is_some() || x.2.is_some()).collect()
+}
@@ -9,6 +9,7 @@ fn drop() {
     with_items_consumed();
     with_items_remained();
     with_forget();
+    with_padding_items();
 
     fn with_items_consumed() {
         // Arrange.
@@ -66,6 +67,26 @@ fn drop() {
         );
         assert_eq!(&vec.to_vec(), rhs);
     }
+
+    fn with_padding_items() {
+        // Arrange.
+        let builder = SparseVecBuilder::new();
+        let vec = &mut builder.build();
+        let range = range_for(builder.len()).include(builder.some_npad_indexs(1)[0]);
+        let inserts = iter::repeat_n(builder.padding(), range.len() + 1);
+        let target = vec.splice(range.clone(), inserts);
+
+        // Act.
+        mem::drop(target);
+
+        // Assert.
+        let stored = builder
+            .npad_indexs()
+            .into_iter()
+            .filter(|x| range.contains(x));
+        assert_eq!(vec.nnp(), builder.nnp() - stored.count());
+        assert!(vec.sparse_reader().all(|x| *x.1 != builder.padding()));
+    }
 }
 
 #[test]